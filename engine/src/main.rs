@@ -1,7 +1,13 @@
+use score_engine::{
+  calculate_decision_decay, compare_reports, export_schemas_with, is_same_item, score_report_text,
+  DecisionDecayConfig, QualityMetrics, ScoreResult, ScoringConfig,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::io::{self, IsTerminal};
 use std::{env, fs};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct DecisionInput {
   title: String,
   context: String,
@@ -11,31 +17,709 @@ struct DecisionInput {
   risks: Vec<String>,
   evidence: Vec<String>,
   confidence: String,
-  createdAtISO: String,
+  #[serde(rename = "createdAtISO")]
+  created_at_iso: String,
   outcome: Option<String>,
+  /// How confidence in the decision decays; `start_date` defaults to `createdAtISO`.
+  #[serde(default)]
+  decay: Option<DecisionDecayConfig>,
 }
 
 #[derive(Debug, Serialize)]
 struct Analysis {
   readiness_score: u32,
   note: String,
+  must_repair: bool,
+  missing_headers: Vec<String>,
+  quality_metrics: QualityMetrics,
+  risks_without_evidence: Vec<usize>,
+  confidence_mismatch: Option<Mismatch>,
+  /// (assumption index, risk index) pairs that describe the same item.
+  assumption_risk_overlap: Vec<(usize, usize)>,
+  /// Overlap between the intent and the report's BEST OPTION/RATIONALE (with `--report`).
+  intent_alignment: Option<f64>,
+  /// Date to revisit the decision (with a `decay` model in the input).
+  critical_review_date: Option<String>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+enum MismatchKind {
+  Overconfident,
+  Underconfident,
+}
+
+#[derive(Debug, Serialize)]
+struct Mismatch {
+  kind: MismatchKind,
+  stated_confidence: String,
+  /// Hedging cues per 100 words of context + intent.
+  hedging_density: f64,
+  hedges: Vec<String>,
+}
+
+/// (predicted readiness, realized score) pairs for decisions with known outcomes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CalibrationStore {
+  pairs: Vec<(f64, f64)>,
+}
+
+const HEDGES: &[&str] = &[
+  "we think", "i think", "probably", "unclear", "maybe", "might", "not sure", "uncertain",
+  "possibly", "perhaps", "hopefully", "it seems", "we believe", "assume", "guess",
+];
+
+const STOPWORDS: &[&str] = &[
+  "that", "this", "with", "from", "into", "than", "then", "they", "them", "will", "would",
+  "could", "should", "have", "been", "were", "what", "when", "which", "while", "about",
+  "over", "under", "more", "less", "some", "many", "very", "just", "only", "also",
+];
+
+/// Lowercased words of 4+ letters that aren't stopwords.
+fn key_terms(text: &str) -> HashSet<String> {
+  text
+    .to_lowercase()
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|w| w.len() >= 4 && !STOPWORDS.contains(w))
+    .map(|w| w.to_string())
+    .collect()
+}
+
+/// Indices of risks whose key terms don't appear in any evidence entry.
+fn risks_without_evidence(input: &DecisionInput) -> Vec<usize> {
+  let evidence_terms: HashSet<String> = input.evidence.iter().flat_map(|e| key_terms(e)).collect();
+
+  input
+    .risks
+    .iter()
+    .enumerate()
+    .filter(|(_, risk)| key_terms(risk).is_disjoint(&evidence_terms))
+    .map(|(i, _)| i)
+    .collect()
+}
+
+/// Pairs of (assumption, risk) indices whose texts are near-identical, i.e.
+/// the same item listed as both a premise and a threat. Uses the engine's
+/// NEXT ACTIONS dedupe measure.
+fn assumption_risk_overlap(input: &DecisionInput) -> Vec<(usize, usize)> {
+  let mut pairs = Vec::new();
+  for (i, assumption) in input.assumptions.iter().enumerate() {
+    for (j, risk) in input.risks.iter().enumerate() {
+      if is_same_item(assumption, risk) {
+        pairs.push((i, j));
+      }
+    }
+  }
+  pairs
+}
+
+/// Lowercased words, keeping apostrophes so "it's" stays one word.
+fn words(text: &str) -> Vec<String> {
+  text
+    .to_lowercase()
+    .split(|c: char| !c.is_alphanumeric() && c != '\'')
+    .filter(|w| !w.is_empty())
+    .map(|w| w.to_string())
+    .collect()
+}
+
+/// Whole-word occurrences of `phrase` in `words`: "might" doesn't match "mighty".
+fn phrase_count(words: &[String], phrase: &str) -> usize {
+  let needle: Vec<&str> = phrase.split(' ').collect();
+  words.windows(needle.len()).filter(|w| w.iter().zip(&needle).all(|(a, b)| a == b)).count()
+}
+
+/// Flags a stated confidence band that the wording of context/intent contradicts:
+/// "high" with heavy hedging, or "low" with none at all in a substantial text.
+fn confidence_language_mismatch(input: &DecisionInput) -> Option<Mismatch> {
+  let text = words(&format!("{} {}", input.context, input.intent));
+  if text.is_empty() {
+    return None;
+  }
+
+  let mut hedges: Vec<String> = Vec::new();
+  let mut hits = 0;
+  for h in HEDGES {
+    let n = phrase_count(&text, h);
+    if n > 0 {
+      hits += n;
+      hedges.push(h.to_string());
+    }
+  }
+  let hedging_density = hits as f64 / text.len() as f64 * 100.0;
+
+  let stated = words(&input.confidence);
+  let kind = if phrase_count(&stated, "high") > 0 && hedging_density >= 3.0 {
+    MismatchKind::Overconfident
+  } else if phrase_count(&stated, "low") > 0 && hits == 0 && text.len() >= 50 {
+    MismatchKind::Underconfident
+  } else {
+    return None;
+  };
+
+  Some(Mismatch {
+    kind,
+    stated_confidence: input.confidence.clone(),
+    hedging_density,
+    hedges,
+  })
+}
+
+/// Body of each `header` in a report, up to the next all-caps `HEADER:` line.
+fn report_sections(report: &str, headers: &[&str]) -> String {
+  let mut out = String::new();
+  let mut inside = false;
+  for line in report.lines() {
+    let trimmed = line.trim().trim_end_matches(':').trim();
+    let is_header = !trimmed.is_empty()
+      && trimmed.chars().any(|c| c.is_alphabetic())
+      && trimmed == trimmed.to_uppercase()
+      && line.trim_end().ends_with(':');
+    if is_header {
+      inside = headers.iter().any(|h| h.eq_ignore_ascii_case(trimmed));
+    } else if inside {
+      out.push_str(line);
+      out.push('\n');
+    }
+  }
+  out
+}
+
+/// Share (0-1) of the intent's key terms echoed in BEST OPTION/RATIONALE.
+/// Terms match on a shared 5-letter stem so "migrate"/"migration" count.
+fn intent_alignment(input: &DecisionInput, report: &str) -> f64 {
+  let intent_terms = key_terms(&input.intent);
+  if intent_terms.is_empty() {
+    return 0.0;
+  }
+
+  let stem = |w: &str| w.chars().take(5).collect::<String>();
+  let report_stems: HashSet<String> = key_terms(&report_sections(report, &["BEST OPTION", "RATIONALE"]))
+    .iter()
+    .map(|w| stem(w))
+    .collect();
+
+  let hits = intent_terms.iter().filter(|t| report_stems.contains(&stem(t))).count();
+  hits as f64 / intent_terms.len() as f64
+}
+
+/// Report-style text built from the input fields, so the input can be scored
+/// like a model-written report. Empty fields leave their header out.
+fn synthesized_report(input: &DecisionInput) -> String {
+  let mut out = String::new();
+  let mut section = |header: &str, lines: Vec<String>| {
+    if lines.iter().all(|l| l.trim().is_empty()) {
+      return;
+    }
+    out.push_str(header);
+    out.push_str(":\n");
+    for line in lines {
+      out.push_str(&line);
+      out.push('\n');
+    }
+    out.push('\n');
+  };
+  let bullets = |items: &[String]| items.iter().map(|i| format!("- {}", i)).collect::<Vec<_>>();
+
+  section("BEST OPTION", vec![input.intent.clone()]);
+  section("RATIONALE", [vec![input.context.clone()], bullets(&input.evidence)].concat());
+  section("OPTIONS", bullets(&input.options));
+  section("TOP RISKS", bullets(&input.risks));
+  section("ASSUMPTIONS TO VALIDATE", bullets(&input.assumptions));
+  out
+}
+
+/// Full score of the synthesized report under the default rubric.
+fn score_input(input: &DecisionInput) -> ScoreResult {
+  score_report_text(&synthesized_report(input), ScoringConfig::default())
+}
+
+/// Half a confidence half-life after the decision was made.
+fn critical_review_date(input: &DecisionInput) -> Option<String> {
+  let mut config = input.decay.clone()?;
+  config.start_date.get_or_insert_with(|| input.created_at_iso.clone());
+  Some(calculate_decision_decay(config).critical_review_date)
+}
+
+/// Engine score of the input; each risk without supporting evidence costs
+/// a further 5 readiness points.
+fn readiness_score(input: &DecisionInput, result: &ScoreResult) -> u32 {
+  result.score.saturating_sub(risks_without_evidence(input).len() as u32 * 5)
+}
+
+/// Store the predicted readiness next to the realized outcome score.
+fn record_outcome(store: &mut CalibrationStore, input: &DecisionInput, realized_score: f64) {
+  let predicted = readiness_score(input, &score_input(input));
+  store.pairs.push((predicted as f64, realized_score));
+}
+
+/// The realized score (0-100) in `input.outcome`, if it has one.
+fn outcome_score(input: &DecisionInput) -> Result<Option<f64>, String> {
+  input.outcome.as_deref().map(parse_outcome).transpose()
+}
+
+/// A realized score, from `outcome` or `--record-outcome`: a number in 0-100.
+fn parse_outcome(outcome: &str) -> Result<f64, String> {
+  match outcome.trim().parse::<f64>() {
+    Ok(score) if (0.0..=100.0).contains(&score) => Ok(score),
+    _ => Err(format!("Invalid outcome score '{}'", outcome)),
+  }
+}
+
+/// Mean of (realized - predicted): negative when past predictions were optimistic.
+fn calibration_offset(store: &CalibrationStore) -> f64 {
+  if store.pairs.is_empty() {
+    return 0.0;
+  }
+  store.pairs.iter().map(|(p, r)| r - p).sum::<f64>() / store.pairs.len() as f64
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+  Json,
+  Yaml,
+  Csv,
+}
+
+impl OutputFormat {
+  fn parse(name: &str) -> Option<Self> {
+    match name.to_ascii_lowercase().as_str() {
+      "json" => Some(Self::Json),
+      "yaml" | "yml" => Some(Self::Yaml),
+      "csv" => Some(Self::Csv),
+      _ => None,
+    }
+  }
+}
+
+/// Serialize `value` in `format`. For CSV a top-level array becomes one row
+/// per element, with the header being the union of their columns.
+fn render<T: Serialize>(value: &T, format: OutputFormat) -> String {
+  match format {
+    OutputFormat::Json => serde_json::to_string_pretty(value).unwrap(),
+    OutputFormat::Yaml => serde_yaml::to_string(value).unwrap(),
+    OutputFormat::Csv => {
+      let rows: Vec<Vec<(String, String)>> = match serde_json::to_value(value).unwrap() {
+        serde_json::Value::Array(items) => items.iter().map(flatten_row).collect(),
+        other => vec![flatten_row(&other)],
+      };
+      let mut header: Vec<&str> = Vec::new();
+      for (key, _) in rows.iter().flatten() {
+        if !header.contains(&key.as_str()) {
+          header.push(key);
+        }
+      }
+
+      let mut lines = vec![header.iter().map(|k| csv_field(k)).collect::<Vec<_>>().join(",")];
+      for row in &rows {
+        let cells: Vec<String> = header
+          .iter()
+          .map(|k| row.iter().find(|(key, _)| key == k).map_or(String::new(), |(_, v)| csv_field(v)))
+          .collect();
+        lines.push(cells.join(","));
+      }
+      lines.join("\n")
+    }
+  }
+}
+
+fn flatten_row(value: &serde_json::Value) -> Vec<(String, String)> {
+  let mut columns = Vec::new();
+  flatten_csv("", value, &mut columns);
+  columns
+}
+
+/// One column per scalar; nested objects become `parent.child` columns and
+/// arrays are joined with "; ".
+fn flatten_csv(prefix: &str, value: &serde_json::Value, out: &mut Vec<(String, String)>) {
+  use serde_json::Value;
+  match value {
+    Value::Object(map) => {
+      for (key, v) in map {
+        let name = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+        match v {
+          Value::Object(_) => flatten_csv(&name, v, out),
+          _ => out.push((name, csv_cell(v))),
+        }
+      }
+    }
+    _ => out.push((prefix.to_string(), csv_cell(value))),
+  }
+}
+
+fn csv_cell(value: &serde_json::Value) -> String {
+  use serde_json::Value;
+  match value {
+    Value::Null => String::new(),
+    Value::String(s) => s.clone(),
+    Value::Array(items) => items.iter().map(csv_cell).collect::<Vec<_>>().join("; "),
+    other => other.to_string(),
+  }
+}
+
+/// RFC 4180 quoting: wrap in quotes when needed, doubling inner quotes.
+fn csv_field(s: &str) -> String {
+  if s.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", s.replace('"', "\"\""))
+  } else {
+    s.to_string()
+  }
+}
+
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a String> {
+  args.iter().position(|a| a == flag).and_then(|i| args.get(i + 1))
+}
+
+const VALUE_FLAGS: &[&str] = &["--calibration", "--record-outcome", "--report", "--format", "--fail-under"];
+
+/// Arguments that are neither flags nor a flag's value, in order.
+fn positionals(args: &[String]) -> Vec<&String> {
+  (1..args.len())
+    .filter(|&i| !args[i].starts_with("--") && !VALUE_FLAGS.contains(&args[i - 1].as_str()))
+    .map(|i| &args[i])
+    .collect()
+}
+
+/// One element of the batch output: an analysis, or why a file was skipped.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum BatchEntry {
+  Analysis(Box<Analysis>),
+  Error { file: String, error: String },
+}
+
+fn load_input(path: &str) -> Result<DecisionInput, String> {
+  let raw = fs::read_to_string(path).map_err(|e| e.to_string())?;
+  serde_json::from_str(&raw).map_err(|e| e.to_string())
+}
+
+/// Analysis of one input, with the calibration `offset` applied to the score.
+/// With a `report` the report itself is scored, otherwise the input fields.
+fn analyze(input: &DecisionInput, offset: f64, report: Option<&str>) -> Analysis {
+  let result = match report {
+    Some(report) => score_report_text(report, ScoringConfig::default()),
+    None => score_input(input),
+  };
+  let score = (readiness_score(input, &result) as f64 + offset).round().clamp(0.0, 100.0) as u32;
+  let source = if report.is_some() { "report" } else { "decision fields" };
+  Analysis {
+    readiness_score: score,
+    note: format!("Scored from {}: {}", source, input.title),
+    must_repair: result.must_repair,
+    missing_headers: result.missing_headers,
+    quality_metrics: result.quality_metrics,
+    risks_without_evidence: risks_without_evidence(input),
+    confidence_mismatch: confidence_language_mismatch(input),
+    assumption_risk_overlap: assumption_risk_overlap(input),
+    intent_alignment: report.map(|report| intent_alignment(input, report)),
+    critical_review_date: critical_review_date(input),
+  }
+}
+
+/// Bad arguments or unreadable/unparseable input.
+const EXIT_INPUT_ERROR: i32 = 1;
+/// The analysis failed a gate: `must_repair`, `--fail-under`, or `--strict`.
+const EXIT_GATE_FAILED: i32 = 2;
+
+const USAGE: &str = "Usage: grounds-engine [<input.json>... | -] [--strict] [--fail-under <score>] [--format json|yaml|csv] [--report <report.txt>] [--calibration <store.json> [--record-outcome <score>]]
+       grounds-engine --compare <before.txt> <after.txt> [--format json|yaml|csv]
+       grounds-engine --schema
+
+Exit codes:
+  0  every analysis passed
+  1  bad arguments or unreadable input
+  2  must_repair is set, the score is below --fail-under, or --strict found risks without evidence";
+
+fn input_error(message: &str) -> ! {
+  eprintln!("{}\n{}", message, USAGE);
+  std::process::exit(EXIT_INPUT_ERROR);
+}
+
+/// Reasons `analysis` fails the exit-code gate; empty when it passes.
+fn gate_failures(analysis: &Analysis, strict: bool, fail_under: Option<u32>) -> Vec<String> {
+  let mut reasons = Vec::new();
+  if analysis.must_repair {
+    reasons.push("report must be repaired".to_string());
+  }
+  if let Some(min) = fail_under.filter(|&min| analysis.readiness_score < min) {
+    reasons.push(format!("score {} is below {}", analysis.readiness_score, min));
+  }
+  // --strict: every risk must be backed by evidence.
+  if strict && !analysis.risks_without_evidence.is_empty() {
+    reasons.push(format!(
+      "strict mode: {} risk(s) lack supporting evidence",
+      analysis.risks_without_evidence.len()
+    ));
+  }
+  reasons
 }
 
 fn main() {
   let args: Vec<String> = env::args().collect();
-  if args.len() < 2 {
-    eprintln!("Usage: grounds-engine <input.json>");
-    std::process::exit(1);
+
+  // JSON Schema for the input and result types, for client codegen.
+  if args.iter().any(|a| a == "--schema") {
+    println!("{}", export_schemas_with(|generator| {
+      generator.subschema_for::<DecisionInput>();
+    }));
+    return;
   }
-  let raw = fs::read_to_string(&args[1]).expect("read file");
-  let input: DecisionInput = serde_json::from_str(&raw).expect("parse json");
 
-  // Placeholder deterministic analysis (the Next.js app contains the full v0.1 heuristics).
-  let score = 70u32;
-  let analysis = Analysis {
-    readiness_score: score,
-    note: format!("Engine placeholder analysis for: {}", input.title),
+  let strict = args.iter().any(|a| a == "--strict");
+  let calibration_path = flag_value(&args, "--calibration");
+  let realized = flag_value(&args, "--record-outcome").map(|v| parse_outcome(v).unwrap_or_else(|e| input_error(&e)));
+  let fail_under = flag_value(&args, "--fail-under")
+    .map(|v| v.parse::<u32>().unwrap_or_else(|_| input_error(&format!("Invalid --fail-under score '{}'", v))));
+  let report_path = flag_value(&args, "--report");
+
+  let format = match flag_value(&args, "--format") {
+    None => OutputFormat::Json,
+    Some(name) => OutputFormat::parse(name).unwrap_or_else(|| input_error(&format!("Unknown format '{}'", name))),
+  };
+
+  // Two drafts of a report: print what changed and stop.
+  if let Some(i) = args.iter().position(|a| a == "--compare") {
+    let (Some(before), Some(after)) = (args.get(i + 1), args.get(i + 2)) else {
+      input_error("--compare needs two report paths");
+    };
+    let read = |p: &String| fs::read_to_string(p).unwrap_or_else(|e| input_error(&format!("Cannot read {}: {}", p, e)));
+    let diff = compare_reports(&read(before), &read(after), ScoringConfig::default());
+    println!("{}", render(&diff, format));
+    return;
+  }
+
+  let paths = positionals(&args);
+  let report = report_path.map(|p| {
+    fs::read_to_string(p).unwrap_or_else(|e| input_error(&format!("Cannot read report {}: {}", p, e)))
+  });
+
+  let mut store: CalibrationStore = calibration_path
+    .and_then(|p| fs::read_to_string(p).ok())
+    .map(|s| serde_json::from_str(&s).unwrap_or_else(|e| input_error(&format!("Invalid calibration store: {}", e))))
+    .unwrap_or_default();
+  let offset = calibration_offset(&store);
+
+  // Several paths: one entry per file, in order; bad files don't stop the batch.
+  if paths.len() > 1 {
+    if realized.is_some() {
+      input_error("--record-outcome takes a single input");
+    }
+    let entries: Vec<BatchEntry> = paths
+      .iter()
+      .map(|path| match load_input(path) {
+        Ok(input) => BatchEntry::Analysis(Box::new(analyze(&input, offset, report.as_deref()))),
+        Err(error) => BatchEntry::Error { file: path.to_string(), error },
+      })
+      .collect();
+    println!("{}", render(&entries, format));
+
+    let mut code = 0;
+    for (path, entry) in paths.iter().zip(&entries) {
+      match entry {
+        BatchEntry::Error { .. } => code = EXIT_INPUT_ERROR,
+        BatchEntry::Analysis(analysis) => {
+          for reason in gate_failures(analysis, strict, fail_under) {
+            eprintln!("{}: {}", path, reason);
+            if code == 0 {
+              code = EXIT_GATE_FAILED;
+            }
+          }
+        }
+      }
+    }
+    std::process::exit(code);
+  }
+
+  // "-", or no path with piped input, reads the decision from stdin.
+  let raw = match paths.first() {
+    Some(p) if *p != "-" => fs::read_to_string(p).unwrap_or_else(|e| input_error(&format!("Cannot read {}: {}", p, e))),
+    None if io::stdin().is_terminal() => input_error("No input given"),
+    _ => io::read_to_string(io::stdin()).unwrap_or_else(|e| input_error(&format!("Cannot read stdin: {}", e))),
+  };
+  let input: DecisionInput =
+    serde_json::from_str(&raw).unwrap_or_else(|e| input_error(&format!("Invalid decision JSON: {}", e)));
+
+  // Known outcome, from --record-outcome or else the input's own: record it and stop.
+  let realized = match realized {
+    None if calibration_path.is_some() => outcome_score(&input).unwrap_or_else(|e| input_error(&e)),
+    realized => realized,
   };
+  if let Some(realized) = realized {
+    let Some(store_path) = calibration_path else {
+      input_error("--record-outcome needs --calibration");
+    };
+    record_outcome(&mut store, &input, realized);
+    fs::write(store_path, serde_json::to_string_pretty(&store).unwrap())
+      .unwrap_or_else(|e| input_error(&format!("Cannot write calibration store {}: {}", store_path, e)));
+    println!("Recorded outcome; suggested offset now {:+.1}", calibration_offset(&store));
+    return;
+  }
+
+  let analysis = analyze(&input, offset, report.as_deref());
+  println!("{}", render(&analysis, format));
+
+  let failures = gate_failures(&analysis, strict, fail_under);
+  for reason in &failures {
+    eprintln!("{}", reason);
+  }
+  if !failures.is_empty() {
+    std::process::exit(EXIT_GATE_FAILED);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn input_with(risks: &[&str], evidence: &[&str]) -> DecisionInput {
+    DecisionInput {
+      title: "Test".to_string(),
+      context: String::new(),
+      intent: String::new(),
+      options: vec![],
+      assumptions: vec![],
+      risks: risks.iter().map(|s| s.to_string()).collect(),
+      evidence: evidence.iter().map(|s| s.to_string()).collect(),
+      confidence: "medium".to_string(),
+      created_at_iso: "2026-01-01T00:00:00Z".to_string(),
+      outcome: None,
+      decay: None,
+    }
+  }
+
+  #[test]
+  fn test_risks_without_evidence() {
+    let input = input_with(
+      &[
+        "Vendor pricing may increase next year",
+        "Team burnout during launch",
+        "Regulatory approval delays",
+      ],
+      &["Vendor quote shows pricing locked for 12 months", "Customer interviews (n=14)"],
+    );
+
+    assert_eq!(risks_without_evidence(&input), vec![1, 2]);
+  }
+
+  #[test]
+  fn test_confidence_language_mismatch() {
+    let mut input = input_with(&[], &[]);
+    input.confidence = "High".to_string();
+    input.context = "We think demand will probably grow, but it's unclear. Maybe churn drops.".to_string();
+    input.intent = "Hopefully we hit the target; possibly next quarter.".to_string();
+
+    let mismatch = confidence_language_mismatch(&input).unwrap();
+    assert_eq!(mismatch.kind, MismatchKind::Overconfident);
+    assert!(mismatch.hedges.contains(&"probably".to_string()));
+
+    input.confidence = "Medium".to_string();
+    assert!(confidence_language_mismatch(&input).is_none());
+
+    // Hedges and bands match whole words only
+    input.confidence = "High".to_string();
+    input.context = "A mighty push; assumptions below plan, nothing to guesswork.".to_string();
+    input.intent = "Ship it.".to_string();
+    assert!(confidence_language_mismatch(&input).is_none());
+    assert_eq!(phrase_count(&words("We might, we MIGHT not; mighty."), "might"), 2);
+    assert_eq!(phrase_count(&words("Honestly, we think so."), "we think"), 1);
+
+    input.confidence = "Below average (allow for slack)".to_string();
+    input.context = "Revenue grew 14% after the March launch and churn fell to 2%. ".repeat(6);
+    assert!(confidence_language_mismatch(&input).is_none());
+    input.confidence = "Low".to_string();
+    assert_eq!(confidence_language_mismatch(&input).unwrap().kind, MismatchKind::Underconfident);
+  }
 
-  println!("{}", serde_json::to_string_pretty(&analysis).unwrap());
+  #[test]
+  fn test_calibration_offset() {
+    let mut input = input_with(&["Vendor pricing may increase"], &["Vendor quote locks pricing for 12 months"]);
+    input.intent = "Adopt the managed queue by 2026-03-31".to_string();
+    input.context = "Self-hosting the queue costs two engineers a quarter.".to_string();
+    input.assumptions = vec!["The vendor meets our 99.9% uptime target".to_string()];
+    let predicted = readiness_score(&input, &score_input(&input)) as f64;
+    assert!(predicted > 20.0, "predicted = {}", predicted);
+
+    let mut store = CalibrationStore::default();
+    for realized in [predicted - 15.0, predicted - 10.0, predicted - 20.0] {
+      record_outcome(&mut store, &input, realized);
+    }
+
+    assert_eq!(store.pairs.len(), 3);
+    assert_eq!(store.pairs[0].0, predicted);
+    assert!((calibration_offset(&store) + 15.0).abs() < 1e-9);
+    assert!(calibration_offset(&store) < 0.0);
+
+    assert_eq!(outcome_score(&input), Ok(None));
+    input.outcome = Some(" 42.5 ".to_string());
+    assert_eq!(outcome_score(&input), Ok(Some(42.5)));
+    input.outcome = Some("shipped late".to_string());
+    assert!(outcome_score(&input).is_err());
+    input.outcome = Some("140".to_string());
+    assert!(outcome_score(&input).is_err());
+  }
+
+  #[test]
+  fn test_synthesized_report() {
+    let mut input = input_with(&["Vendor lock-in"], &["Quote locked for 12 months"]);
+    input.intent = "Adopt the managed queue".to_string();
+    input.context = "Self-hosting costs two engineers a quarter.".to_string();
+
+    let report = synthesized_report(&input);
+    assert!(report.starts_with("BEST OPTION:\nAdopt the managed queue\n"));
+    assert!(report.contains("RATIONALE:\nSelf-hosting costs two engineers a quarter.\n- Quote locked for 12 months\n"));
+    assert!(!report.contains("ASSUMPTIONS TO VALIDATE:"));
+
+    let result = score_input(&input);
+    assert!(result.missing_headers.contains(&"NEXT ACTIONS".to_string()));
+    assert!(!result.missing_headers.contains(&"TOP RISKS".to_string()));
+  }
+
+  #[test]
+  fn test_intent_alignment() {
+    let mut input = input_with(&[], &[]);
+    input.intent = "Reduce checkout latency by migrating payments to the regional cluster".to_string();
+
+    let on_topic = "BEST OPTION:\nMigrate payments to the regional cluster.\n\nRATIONALE:\n- Cuts checkout latency roughly in half\n\nNEXT ACTIONS:\n1. Book the migration window\n";
+    let off_topic = "BEST OPTION:\nRebrand the marketing site.\n\nRATIONALE:\n- Fresh visual identity for the spring campaign\n\nNEXT ACTIONS:\n1. Reduce checkout latency in the regional cluster\n";
+
+    let aligned = intent_alignment(&input, on_topic);
+    let drifted = intent_alignment(&input, off_topic);
+    assert!(aligned > 0.8, "aligned = {}", aligned);
+    assert!(drifted < 0.2, "drifted = {}", drifted);
+  }
+
+  #[test]
+  fn test_assumption_risk_overlap() {
+    let mut input = input_with(
+      &["Key supplier cannot deliver components before launch", "Team burnout during launch"],
+      &[],
+    );
+    input.assumptions = vec![
+      "Budget approved by finance".to_string(),
+      "Key supplier can deliver components before launch".to_string(),
+    ];
+
+    assert_eq!(assumption_risk_overlap(&input), vec![(1, 0)]);
+  }
+
+  #[test]
+  fn test_critical_review_date() {
+    let mut input = input_with(&[], &[]);
+    assert_eq!(critical_review_date(&input), None);
+
+    // Linear at 0.5%/day halves at day 100: review 50 days after createdAtISO
+    input.created_at_iso = "2025-12-01T09:00:00Z".to_string();
+    input.decay = Some(
+      serde_json::from_value(serde_json::json!({
+        "initial_confidence": 80.0,
+        "decay_factors": [{ "name": "Market", "decay_rate": 0.5, "volatility": 0.1 }],
+        "time_horizon_days": 365,
+        "decay_model": "Linear",
+      }))
+      .unwrap(),
+    );
+    assert_eq!(critical_review_date(&input).as_deref(), Some("2026-01-20"));
+
+    input.decay.as_mut().unwrap().start_date = Some("2026-03-01".to_string());
+    assert_eq!(critical_review_date(&input).as_deref(), Some("2026-04-20"));
+  }
 }
@@ -0,0 +1,183 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn exit_code(args: &[&str]) -> Option<i32> {
+  Command::new(env!("CARGO_BIN_EXE_grounds-engine"))
+    .args(args)
+    .current_dir(env!("CARGO_MANIFEST_DIR"))
+    .output()
+    .expect("run grounds-engine")
+    .status
+    .code()
+}
+
+fn run(args: &[&str]) -> String {
+  let output = Command::new(env!("CARGO_BIN_EXE_grounds-engine"))
+    .args(args)
+    .current_dir(env!("CARGO_MANIFEST_DIR"))
+    .output()
+    .expect("run grounds-engine");
+  String::from_utf8(output.stdout).expect("utf-8 output")
+}
+
+#[test]
+fn scores_fixture_with_real_engine() {
+  let analysis: serde_json::Value = serde_json::from_str(&run(&["tests/fixtures/decision.json"])).unwrap();
+
+  let score = analysis["readiness_score"].as_u64().unwrap();
+  assert_ne!(score, 70, "placeholder score");
+  assert!(score > 0 && score < 100);
+  assert_eq!(analysis["must_repair"], true);
+
+  let missing: Vec<&str> = analysis["missing_headers"]
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|h| h.as_str().unwrap())
+    .collect();
+  assert!(missing.contains(&"NEXT ACTIONS"));
+  assert!(!missing.contains(&"BEST OPTION"));
+  assert!(analysis["quality_metrics"]["overall_quality"].as_f64().unwrap() > 0.0);
+}
+
+#[test]
+fn output_formats_parse() {
+  let json: serde_json::Value =
+    serde_json::from_str(&run(&["tests/fixtures/decision.json", "--format", "json"])).unwrap();
+  let score = json["readiness_score"].as_u64().unwrap();
+
+  let yaml: serde_yaml::Value = serde_yaml::from_str(&run(&["tests/fixtures/decision.json", "--format", "yaml"])).unwrap();
+  assert_eq!(yaml["readiness_score"].as_u64(), Some(score));
+  assert_eq!(yaml["missing_headers"][0].as_str(), json["missing_headers"][0].as_str());
+
+  let csv = run(&["tests/fixtures/decision.json", "--format", "csv"]);
+  let mut reader = csv::Reader::from_reader(csv.as_bytes());
+  let headers = reader.headers().unwrap().clone();
+  let rows: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+  assert_eq!(rows.len(), 1);
+  let column = |name: &str| {
+    let i = headers.iter().position(|h| h == name).unwrap();
+    rows[0][i].to_string()
+  };
+  assert_eq!(column("readiness_score"), score.to_string());
+  assert_eq!(column("missing_headers"), "HALF-LIFE; BLIND SPOTS; NEXT ACTIONS");
+  assert!(column("quality_metrics.clarity_score").parse::<f64>().is_ok());
+  assert_eq!(column("note"), "Scored from decision fields: Move payments to the regional cluster");
+}
+
+#[test]
+fn reads_decision_from_stdin() {
+  let fixture = std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/decision.json")).unwrap();
+  let from_file: serde_json::Value = serde_json::from_str(&run(&["tests/fixtures/decision.json"])).unwrap();
+
+  for args in [&["-"][..], &[][..]] {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_grounds-engine"))
+      .args(args)
+      .stdin(Stdio::piped())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::null())
+      .spawn()
+      .expect("run grounds-engine");
+    child.stdin.take().unwrap().write_all(&fixture).unwrap();
+    let output = child.wait_with_output().unwrap();
+    // Scored from the fields alone, so NEXT ACTIONS is missing
+    assert_eq!(output.status.code(), Some(2));
+
+    let from_stdin: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(from_stdin["readiness_score"], from_file["readiness_score"]);
+    assert!(from_stdin["readiness_score"].as_u64().unwrap() > 0);
+  }
+}
+
+#[test]
+fn batch_reports_errors_inline() {
+  let output = run(&[
+    "tests/fixtures/decision.json",
+    "tests/fixtures/malformed.json",
+    "tests/fixtures/hiring.json",
+  ]);
+  let entries: Vec<serde_json::Value> = serde_json::from_str(&output).unwrap();
+  assert_eq!(entries.len(), 3);
+
+  assert!(entries[0]["note"].as_str().unwrap().ends_with("Move payments to the regional cluster"));
+  assert_eq!(entries[1]["file"], "tests/fixtures/malformed.json");
+  assert!(entries[1]["error"].as_str().unwrap().contains("EOF"));
+  assert!(entries[1].get("readiness_score").is_none());
+  assert!(entries[2]["note"].as_str().unwrap().ends_with("Hire a second on-call engineer"));
+  assert!(entries[2]["readiness_score"].as_u64().is_some());
+}
+
+#[test]
+fn exit_codes_gate_ci() {
+  let decision = "tests/fixtures/decision.json";
+  let report = "tests/fixtures/report.txt";
+
+  assert_eq!(exit_code(&[decision, "--report", report]), Some(0));
+  assert_eq!(exit_code(&[decision]), Some(2), "must_repair");
+  assert_eq!(exit_code(&[decision, "--report", report, "--fail-under", "90"]), Some(0));
+  assert_eq!(exit_code(&[decision, "--report", report, "--fail-under", "96"]), Some(2));
+  assert_eq!(exit_code(&[decision, "--report", report, "--strict"]), Some(2));
+
+  assert_eq!(exit_code(&["tests/fixtures/malformed.json"]), Some(1));
+  assert_eq!(exit_code(&["tests/fixtures/missing.json"]), Some(1));
+  assert_eq!(exit_code(&[decision, "--fail-under", "high"]), Some(1));
+  assert_eq!(exit_code(&[decision, "tests/fixtures/malformed.json", "--report", report]), Some(1));
+}
+
+#[test]
+fn compare_mode_diffs_drafts() {
+  let diff: serde_json::Value = serde_json::from_str(&run(&[
+    "--compare",
+    "tests/fixtures/report_draft.txt",
+    "tests/fixtures/report.txt",
+  ]))
+  .unwrap();
+
+  assert_eq!(diff["headers_added"], serde_json::json!(["NEXT ACTIONS"]));
+  assert_eq!(diff["headers_removed"], serde_json::json!([]));
+  assert!(diff["score_delta"].as_i64().unwrap() > 0);
+  assert!(diff["quality_deltas"].as_array().unwrap().iter().any(|d| d["metric"] == "overall_quality"));
+}
+
+#[test]
+fn schema_covers_input_and_result() {
+  let schema: serde_json::Value = serde_json::from_str(&run(&["--schema"])).unwrap();
+  let defs = &schema["$defs"];
+
+  assert!(defs["ScoreResult"]["properties"]["score"].is_object());
+  assert!(defs["MonteCarloResult"].is_object());
+  assert!(defs["DecisionInput"]["properties"]["createdAtISO"].is_object());
+}
+
+#[test]
+fn records_outcome_from_input() {
+  let dir = std::env::temp_dir().join(format!("grounds-engine-outcome-{}", std::process::id()));
+  std::fs::create_dir_all(&dir).unwrap();
+  let input = dir.join("decision.json");
+  let store = dir.join("store.json");
+  let fixture = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/decision.json")).unwrap();
+  std::fs::write(&input, fixture.replace("\"outcome\": null", "\"outcome\": \"40\"")).unwrap();
+
+  let args = [input.to_str().unwrap(), "--calibration", store.to_str().unwrap()];
+  assert_eq!(exit_code(&args), Some(0));
+  let saved: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&store).unwrap()).unwrap();
+  assert_eq!(saved["pairs"][0][1], 40.0);
+
+  // --record-outcome wins over the input's outcome
+  assert_eq!(exit_code(&[args[0], args[1], args[2], "--record-outcome", "70"]), Some(0));
+  let saved: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&store).unwrap()).unwrap();
+  assert_eq!(saved["pairs"][1][1], 70.0);
+
+  // Out-of-range flag values never reach the store
+  for bad in ["140", "-5", "NaN"] {
+    assert_eq!(exit_code(&[args[0], args[1], args[2], "--record-outcome", bad]), Some(1));
+  }
+  let saved: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&store).unwrap()).unwrap();
+  assert_eq!(saved["pairs"].as_array().unwrap().len(), 2);
+
+  // Unwritable store is an input error, not a panic
+  let unwritable = dir.join("missing").join("store.json");
+  assert_eq!(exit_code(&[args[0], "--calibration", unwritable.to_str().unwrap()]), Some(1));
+
+  std::fs::remove_dir_all(&dir).unwrap();
+}
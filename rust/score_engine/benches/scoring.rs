@@ -0,0 +1,41 @@
+//! Scoring throughput. Fixed regexes and the built-in lexicons are built on
+//! first use, so every iteration after the first measures scoring alone.
+//!
+//! `cargo bench --features bench`
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use score_engine::{minimal_template, score_report_reader, score_report_text, ScoringConfig};
+
+fn large_report(cfg: &ScoringConfig) -> String {
+    let mut report = minimal_template(cfg);
+    for i in 0..2000 {
+        report.push_str(&format!("\n- Dana reviews vendor invoice {} by Friday, cutting spend 3%", i));
+    }
+    report
+}
+
+fn scoring(c: &mut Criterion) {
+    let cfg = ScoringConfig::default();
+    let template = minimal_template(&cfg);
+    let large = large_report(&cfg);
+
+    c.bench_function("score_report_text/template", |b| {
+        b.iter(|| score_report_text(black_box(&template), cfg.clone()))
+    });
+    c.bench_function("score_report_text/batch_of_100", |b| {
+        b.iter(|| {
+            for _ in 0..100 {
+                score_report_text(black_box(&template), cfg.clone());
+            }
+        })
+    });
+    c.bench_function("score_report_text/2000_lines", |b| {
+        b.iter(|| score_report_text(black_box(&large), cfg.clone()))
+    });
+    c.bench_function("score_report_reader/2000_lines", |b| {
+        b.iter(|| score_report_reader(black_box(large.as_bytes()), cfg.clone()))
+    });
+}
+
+criterion_group!(benches, scoring);
+criterion_main!(benches);
@@ -2,17 +2,21 @@
 // Enhanced Decision Intelligence Score Engine
 // Features: Validation, Monte Carlo Simulation, Sensitivity Analysis, NLP Scoring
 
-mod wasm;
+pub mod wasm;
 
+use chrono::NaiveDate;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::{LazyLock, Mutex};
 
 // ============================================================================
 // CORE SCORING TYPES
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ScoreResult {
     pub score: u32,
     pub must_repair: bool,
@@ -20,28 +24,103 @@ pub struct ScoreResult {
 
     pub missing_headers: Vec<String>,
     pub empty_sections: Vec<String>,
+    /// Sections under `ScoringConfig::min_section_words`; penalized like
+    /// empty sections.
+    pub thin_sections: Vec<String>,
     pub duplicate_headers: Vec<String>,
-
+    /// Headers out of `required_headers` order; only checked with
+    /// `ScoringConfig::enforce_header_order`.
+    pub misordered_headers: Vec<String>,
+    /// Present sections holding unfilled placeholders (TODO, TBD, `[...]`, `<...>`).
+    pub placeholder_sections: Vec<String>,
+    /// Claims in one section that another section contradicts.
+    pub contradictions: Vec<String>,
+    /// Trimmed body of each present required header, keyed by header, so
+    /// callers don't have to re-split the report.
+    pub sections: HashMap<String, String>,
+    /// Verb/owner/deadline check for each NEXT ACTIONS item, in order.
+    pub next_action_quality: Vec<ActionQuality>,
+    /// NEXT ACTIONS items that restate an earlier item (padding).
+    pub duplicate_next_actions: Vec<String>,
+    /// Headers missing in draft mode that the author deferred (not penalized).
+    pub deferred_headers: Vec<String>,
+
+    /// NEXT ACTIONS items, not counting `duplicate_next_actions`.
     pub next_actions_count: usize,
     pub next_actions_ok: bool,
+    pub next_actions_required: usize,
+    /// Fraction of required headers present (0.0-1.0), independent of penalties.
+    pub structural_completeness: f64,
+    /// Blend of structural score and text quality (0-100), see `structural_weight`.
+    pub composite_score: f64,
 
     pub truncation_suspected: bool,
+    /// Rollback/exit cues were found ("kill switch", "plan B", ...).
+    pub has_exit_plan: bool,
+    /// Strength of the exit plan (0.0-1.0); cues in BLIND SPOTS or
+    /// NEXT ACTIONS count double.
+    pub exit_plan_score: f64,
+    /// Points added back for the exit plan, see `ScoringConfig::exit_plan_bonus`.
+    pub exit_plan_bonus: u32,
     pub notes: Vec<String>,
+    /// Structured penalties, in the order they were applied.
+    pub penalties: Vec<Penalty>,
     
     pub quality_metrics: QualityMetrics,
+    /// Clarity of each present required section on its own (0.0-1.0).
+    pub section_readability: HashMap<String, f64>,
     pub confidence_interval: ConfidenceInterval,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Penalty {
+    pub label: String,
+    pub points: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaterfallStep {
+    pub label: String,
+    /// Points deducted by this step (negative when a step adds points back).
+    pub delta: i32,
+    pub running_total: i32,
+}
+
+/// One signed component of `score_contributions`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Contribution {
+    pub label: String,
+    pub points: i32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct QualityMetrics {
     pub clarity_score: f64,
     pub specificity_score: f64,
     pub actionability_score: f64,
     pub completeness_score: f64,
     pub overall_quality: f64,
+    /// Sentences longer than `ScoringConfig::run_on_sentence_words`.
+    pub run_on_sentences: usize,
+    /// 1.0 = no unexplained acronyms; drops as their density rises.
+    pub jargon_score: f64,
+    /// How firmly BEST OPTION commits (1.0 = no qualifiers); 0 when the
+    /// section is absent. Not part of `overall_quality`.
+    pub decisiveness_score: f64,
+    /// Moving-average type-token ratio (0.0-1.0); low for repetitive,
+    /// boilerplate text.
+    #[serde(default)]
+    pub lexical_diversity: f64,
+    /// Share of sentences in the passive voice (0.0-1.0), see
+    /// `passive_voice_ratio`. Trims `actionability_score` by up to 20%.
+    #[serde(default)]
+    pub passive_voice_ratio: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ConfidenceInterval {
     pub lower_bound: f64,
     pub upper_bound: f64,
@@ -50,40 +129,597 @@ pub struct ConfidenceInterval {
 
 #[derive(Debug, Clone)]
 pub struct ScoringConfig {
-    pub required_headers: Vec<&'static str>,
+    pub required_headers: Vec<String>,
     pub min_next_actions: usize,
     pub enable_quality_metrics: bool,
     pub enable_monte_carlo: bool,
+    /// Lowest score a structurally salvageable report can receive.
+    /// Applied only when at least half of `required_headers` are present,
+    /// so heavy penalties don't erase the signal of an otherwise usable report.
+    pub min_floor_score: u32,
+    /// Language code used to pick a lexicon; `None` guesses from the text.
+    pub language: Option<String>,
+    /// Skip header-like lines inside `>` blockquotes or quotation marks,
+    /// so quoted template instructions don't count as sections.
+    pub ignore_quoted_headers: bool,
+    /// Word count above which a single sentence is treated as a run-on.
+    pub run_on_sentence_words: usize,
+    /// Drafting: headers listed in `allowed_missing` are deferred, not penalized.
+    pub draft_mode: bool,
+    pub allowed_missing: Vec<String>,
+    /// Rules for `finish_reason_hint`, evaluated top-down; the first match wins.
+    pub finish_reason_rules: Vec<FinishReasonRule>,
+    pub recommendation_thresholds: RecommendationThresholds,
+    /// Manual report type; its NEXT ACTIONS threshold overrides `min_next_actions`.
+    pub report_type: Option<ReportType>,
+    /// Guess the report type from the text when `report_type` is unset.
+    pub auto_detect_report_type: bool,
+    /// Share of NEXT ACTIONS one owner may hold before it's flagged as concentration risk.
+    pub max_owner_share: f64,
+    /// Weight of the structural `score` in `composite_score`; the rest goes to
+    /// `quality_metrics.overall_quality` (scaled to 0-100).
+    pub structural_weight: f64,
+    /// Points granted to a report with a full-strength exit plan, scaled by
+    /// `exit_plan_score`; 0 disables the bonus.
+    pub exit_plan_bonus: u32,
+    pub penalty_weights: PenaltyWeights,
+    /// Missing-header penalty per header in `required_headers`; headers not
+    /// listed cost `PenaltyWeights::missing_header`.
+    pub header_weights: HashMap<String, u32>,
+    pub quality_weights: QualityWeights,
+    pub header_match_mode: HeaderMatchMode,
+    /// Replace the lexicon's vague words (specificity) for domain writing.
+    pub vague_words: Option<Vec<String>>,
+    /// Replace the lexicon's action verbs (actionability) for domain writing.
+    pub action_verbs: Option<Vec<String>>,
+    /// Penalize NEXT ACTIONS items without an owner or a deadline, see
+    /// `PenaltyWeights::unsmart_action`.
+    pub require_smart_actions: bool,
+    /// Words a present section needs to not count as thin; 0 disables.
+    pub min_section_words: usize,
+    /// Require sections in `required_headers` order; see
+    /// `PenaltyWeights::misordered_header`.
+    pub enforce_header_order: bool,
+    /// A suspected truncation forces `must_repair` only when the score is
+    /// below this.
+    pub truncation_repair_below: u32,
+    /// Also suspect truncation when the last line is an unfinished sentence
+    /// (no terminal punctuation); off keeps the suffix-only checks.
+    pub check_sentence_completeness: bool,
+}
+
+/// How header lines are matched against `required_headers`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum HeaderMatchMode {
+    Exact,
+    /// Accept header-like lines within this Levenshtein distance ("TOP RISK",
+    /// "BEST OPTIONS") and treat them as the required header.
+    Fuzzy(u8),
+}
+
+/// Report templates by decision weight, mirroring the app's template difficulty.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ReportType {
+    Lite,     // simple
+    Standard, // moderate
+    Major,    // complex
+}
+
+impl ReportType {
+    pub fn min_next_actions(&self) -> usize {
+        match self {
+            ReportType::Lite => 3,
+            ReportType::Standard => 6,
+            ReportType::Major => 8,
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// NEXT ACTIONS threshold for `input`: manual report type, then
+    /// auto-detected type, then the plain `min_next_actions`.
+    pub fn effective_min_next_actions(&self, input: &str) -> usize {
+        match (self.report_type, self.auto_detect_report_type) {
+            (Some(t), _) => t.min_next_actions(),
+            (None, true) => classify_report_type(input).min_next_actions(),
+            (None, false) => self.min_next_actions,
+        }
+    }
+}
+
+/// Cut-offs for the go/no-go `recommendation`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecommendationThresholds {
+    pub proceed_min_score: u32,
+    pub caution_min_score: u32,
+    /// Below this the report is rejected outright.
+    pub revise_min_score: u32,
+    /// Monte Carlo failure risk above which `Proceed` is downgraded to caution.
+    pub caution_failure_risk: f64,
+    /// Monte Carlo failure risk above which the decision must be revised.
+    pub revise_failure_risk: f64,
+}
+
+impl Default for RecommendationThresholds {
+    fn default() -> Self {
+        Self {
+            proceed_min_score: 85,
+            caution_min_score: 70,
+            revise_min_score: 40,
+            caution_failure_risk: 0.10,
+            revise_failure_risk: 0.30,
+        }
+    }
+}
+
+/// Points deducted per structural problem by `score_report_text`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PenaltyWeights {
+    pub missing_header: u32,
+    pub empty_section: u32,
+    pub duplicate_header: u32,
+    pub truncation: u32,
+    /// Flat penalty once NEXT ACTIONS falls short, plus `next_actions_per_deficit`
+    /// for each missing action.
+    pub next_actions_base: u32,
+    pub next_actions_per_deficit: u32,
+    /// Per section with placeholders, see `detect_placeholders`.
+    pub placeholder_section: u32,
+    /// Per claim one section makes and another contradicts, see
+    /// `detect_contradictions`.
+    pub contradiction: u32,
+    /// Per NEXT ACTIONS item lacking an owner or deadline; only applied
+    /// with `ScoringConfig::require_smart_actions`.
+    pub unsmart_action: u32,
+    /// Per header out of order; only applied with
+    /// `ScoringConfig::enforce_header_order`.
+    pub misordered_header: u32,
+}
+
+impl Default for PenaltyWeights {
+    fn default() -> Self {
+        Self {
+            missing_header: 12,
+            empty_section: 8,
+            duplicate_header: 6,
+            truncation: 12,
+            next_actions_base: 10,
+            next_actions_per_deficit: 3,
+            placeholder_section: 5,
+            contradiction: 4,
+            unsmart_action: 2,
+            misordered_header: 3,
+        }
+    }
+}
+
+/// How the four quality scores mix into `QualityMetrics::overall_quality`.
+/// The weights should sum to 1 so the result stays in 0-1.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct QualityWeights {
+    pub clarity: f64,
+    pub specificity: f64,
+    pub actionability: f64,
+    pub completeness: f64,
+}
+
+impl Default for QualityWeights {
+    fn default() -> Self {
+        Self {
+            clarity: 0.25,
+            specificity: 0.30,
+            actionability: 0.25,
+            completeness: 0.20,
+        }
+    }
+}
+
+/// Governance rubric: the JSON form of the tunable parts of `ScoringConfig`.
+/// Unknown fields are rejected so a misspelled name can't silently fall
+/// back to its default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rubric {
+    pub headers: Vec<String>,
+    /// Points for missing a given header, overriding
+    /// `PenaltyWeights::missing_header` for that header.
+    #[serde(default)]
+    pub header_weights: HashMap<String, u32>,
+    pub min_next_actions: usize,
+    pub structural_weight: f64,
+    pub thresholds: RecommendationThresholds,
+    #[serde(default)]
+    pub min_floor_score: u32,
+    #[serde(default = "default_run_on_sentence_words")]
+    pub run_on_sentence_words: usize,
+    #[serde(default = "default_max_owner_share")]
+    pub max_owner_share: f64,
+    #[serde(default)]
+    pub penalty_weights: PenaltyWeights,
+    #[serde(default)]
+    pub quality_weights: QualityWeights,
+}
+
+fn default_run_on_sentence_words() -> usize {
+    40
+}
+
+fn default_max_owner_share() -> f64 {
+    0.5
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RubricError {
+    /// Not valid JSON, or a field has the wrong type.
+    Malformed(String),
+    MissingField(String),
+    InvalidField { field: String, reason: String },
+}
+
+impl std::fmt::Display for RubricError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RubricError::Malformed(msg) => write!(f, "malformed rubric: {}", msg),
+            RubricError::MissingField(field) => write!(f, "rubric is missing `{}`", field),
+            RubricError::InvalidField { field, reason } => {
+                write!(f, "invalid rubric field `{}`: {}", field, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RubricError {}
+
+/// Why a report could not be scored under a given config.
+#[derive(Debug)]
+pub enum ScoreError {
+    /// A required header that can never match a header line (blank or multi-line).
+    InvalidHeader(String),
+    /// A pattern built from the config failed to compile.
+    Pattern(regex::Error),
+    /// The report could not be read, or was not UTF-8.
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for ScoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScoreError::InvalidHeader(h) => write!(f, "invalid required header {:?}", h),
+            ScoreError::Pattern(e) => write!(f, "invalid header pattern: {}", e),
+            ScoreError::Io(e) => write!(f, "could not read report: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ScoreError {}
+
+impl From<regex::Error> for ScoreError {
+    fn from(e: regex::Error) -> Self {
+        ScoreError::Pattern(e)
+    }
+}
+
+impl From<std::io::Error> for ScoreError {
+    fn from(e: std::io::Error) -> Self {
+        ScoreError::Io(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum Recommendation {
+    Proceed,
+    ProceedWithCaution,
+    Revise,
+    Reject,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum HintCondition {
+    TruncationSuspected,
+    MustRepair,
+    MissingHeaders,
+    EmptySections,
+    DuplicateHeaders,
+    NextActionsShort,
+    ScoreBelow(u32),
+    Always,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinishReasonRule {
+    pub condition: HintCondition,
+    pub label: String,
+}
+
+impl FinishReasonRule {
+    pub fn new(condition: HintCondition, label: &str) -> Self {
+        Self {
+            condition,
+            label: label.to_string(),
+        }
+    }
+}
+
+/// Strings for the three built-in hint outcomes, for pipelines with their
+/// own finish-reason enum.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FinishReasonLabels {
+    pub truncated: String,
+    pub incomplete: String,
+    pub ok: String,
+}
+
+impl Default for FinishReasonLabels {
+    fn default() -> Self {
+        Self {
+            truncated: "LIKELY_TRUNCATED".to_string(),
+            incomplete: "INCOMPLETE_STRUCTURE".to_string(),
+            ok: "OK".to_string(),
+        }
+    }
+}
+
+impl FinishReasonLabels {
+    /// The built-in rules with these labels.
+    pub fn rules(&self) -> Vec<FinishReasonRule> {
+        vec![
+            FinishReasonRule::new(HintCondition::TruncationSuspected, &self.truncated),
+            FinishReasonRule::new(HintCondition::MustRepair, &self.incomplete),
+            FinishReasonRule::new(HintCondition::Always, &self.ok),
+        ]
+    }
+}
+
+/// The built-in hints: truncation, then incomplete structure, otherwise OK.
+pub fn default_finish_reason_rules() -> Vec<FinishReasonRule> {
+    FinishReasonLabels::default().rules()
 }
 
 impl Default for ScoringConfig {
     fn default() -> Self {
         Self {
-            required_headers: vec![
-                "BEST OPTION",
-                "RATIONALE",
-                "TOP RISKS",
-                "ASSUMPTIONS TO VALIDATE",
-                "HALF-LIFE",
-                "BLIND SPOTS",
-                "NEXT ACTIONS",
-            ],
+            required_headers: to_strings(&DEFAULT_HEADERS),
             min_next_actions: 6,
             enable_quality_metrics: true,
             enable_monte_carlo: true,
+            min_floor_score: 0,
+            language: Some("en".to_string()),
+            ignore_quoted_headers: false,
+            run_on_sentence_words: 40,
+            draft_mode: false,
+            allowed_missing: Vec::new(),
+            finish_reason_rules: default_finish_reason_rules(),
+            recommendation_thresholds: RecommendationThresholds::default(),
+            report_type: None,
+            auto_detect_report_type: false,
+            max_owner_share: 0.5,
+            structural_weight: 0.7,
+            exit_plan_bonus: 0,
+            penalty_weights: PenaltyWeights::default(),
+            header_weights: HashMap::new(),
+            quality_weights: QualityWeights::default(),
+            header_match_mode: HeaderMatchMode::Exact,
+            vague_words: None,
+            action_verbs: None,
+            require_smart_actions: false,
+            min_section_words: 0,
+            enforce_header_order: false,
+            truncation_repair_below: 92,
+            check_sentence_completeness: true,
         }
     }
 }
 
+// ============================================================================
+// LEXICON TYPES
+// ============================================================================
+
+/// Language-specific word lists used by the NLP quality metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lexicon {
+    pub language: String,
+    pub vague_words: Vec<String>,
+    pub action_verbs: Vec<String>,
+    /// Common function words used to guess the language of a report.
+    pub stopwords: Vec<String>,
+    /// Regexes that signal concrete detail, each with its specificity bonus.
+    #[serde(default = "default_specific_patterns")]
+    pub specific_patterns: Vec<SpecificPattern>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecificPattern {
+    pub pattern: String,
+    pub weight: f64,
+}
+
+/// Lexicons keyed by language code. English is the default fallback.
+#[derive(Debug, Clone)]
+pub struct LexiconRegistry {
+    lexicons: HashMap<String, Lexicon>,
+    default_language: String,
+}
+
+// ============================================================================
+// PII REDACTION TYPES
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum PiiKind {
+    Email,
+    Phone,
+    Name,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Redaction {
+    pub kind: PiiKind,
+    /// Byte span of the redacted value in the original text.
+    pub start: usize,
+    pub end: usize,
+    pub placeholder: String,
+}
+
+// ============================================================================
+// SECTION ANALYSIS TYPES
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MitigationCoverage {
+    pub total_risks: usize,
+    pub mitigated_risks: usize,
+    /// Fraction of risks paired with a mitigation (1.0 when there are no risks).
+    pub coverage: f64,
+    pub unpaired_risks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionMetrics {
+    pub header: String,
+    pub present: bool,
+    pub metrics: QualityMetrics,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionDelta {
+    pub header: String,
+    pub input_quality: f64,
+    pub exemplar_quality: f64,
+    /// `input_quality - exemplar_quality`
+    pub delta: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonReport {
+    /// `input.score - exemplar.score`
+    pub score_delta: i32,
+    pub composite_delta: f64,
+    pub section_deltas: Vec<SectionDelta>,
+    /// Sections whose quality trails the exemplar by more than 0.05.
+    pub below_exemplar: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricDelta {
+    pub metric: String,
+    pub before: f64,
+    pub after: f64,
+    /// `after - before`
+    pub delta: f64,
+}
+
+/// What changed between two drafts of the same report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportDiff {
+    pub score_before: u32,
+    pub score_after: u32,
+    /// `after.score - before.score`
+    pub score_delta: i32,
+    /// Required headers missing before and present after.
+    pub headers_added: Vec<String>,
+    /// Required headers present before and missing after.
+    pub headers_removed: Vec<String>,
+    pub quality_deltas: Vec<MetricDelta>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ValidationStatus {
+    Validated,
+    Pending,
+    Invalidated,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Assumption {
+    /// Item text with the status cue removed.
+    pub text: String,
+    pub status: ValidationStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnerConcentration {
+    pub total_actions: usize,
+    /// Actions per owner, most loaded first.
+    pub owners: Vec<(String, usize)>,
+    pub unowned_actions: usize,
+    pub top_owner: Option<String>,
+    /// Top owner's share of all NEXT ACTIONS.
+    pub top_share: f64,
+    pub concentrated: bool,
+}
+
+/// One data row of a Markdown NEXT ACTIONS table (`action | owner | due`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TableAction {
+    pub action: String,
+    pub owner: Option<String>,
+    pub due: Option<String>,
+}
+
+/// Per-item check of a NEXT ACTIONS entry: does it say what, who and when.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ActionQuality {
+    pub action: String,
+    pub has_verb: bool,
+    pub has_owner: bool,
+    pub has_deadline: bool,
+}
+
+// ============================================================================
+// PORTFOLIO TYPES
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioScore {
+    pub total_weight: f64,
+    /// Weight-weighted mean of the decisions' scores.
+    pub weighted_score: f64,
+    /// Index of the lowest-scoring decision.
+    pub worst_index: Option<usize>,
+    pub worst_score: Option<u32>,
+    /// Fraction of total weight sitting in `must_repair` decisions.
+    pub must_repair_share: f64,
+}
+
 // ============================================================================
 // MONTE CARLO SIMULATION TYPES
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MonteCarloConfig {
     pub iterations: usize,
     pub seed: Option<u64>,
     pub confidence_level: f64,
+    /// Run a kernel-density peak count over the outcomes to flag bimodal results.
+    #[serde(default)]
+    pub detect_modality: bool,
+    /// Record each risk's share of the total simulated loss.
+    #[serde(default)]
+    pub track_contributions: bool,
+    /// Score cut-offs for `failure_curve`, e.g. `[40, 50, 60, 70, 80]`; empty skips it.
+    #[serde(default)]
+    pub failure_thresholds: Vec<f64>,
+    /// `(probability, risk-probability multiplier)` per regime, e.g. recession
+    /// vs. growth. Each iteration samples one regime and scales every risk's
+    /// probability by its multiplier; empty means a single neutral regime.
+    #[serde(default)]
+    pub regime_priors: Vec<(f64, f64)>,
+    /// Risk-by-risk correlation of occurrence (one row per risk, 1.0 on the
+    /// diagonal). `None`, or a matrix that is the wrong size or not positive
+    /// semi-definite, samples risks independently.
+    #[serde(default)]
+    pub correlation_matrix: Option<Vec<Vec<f64>>>,
+    /// Number of equal-width bins in `MonteCarloResult::histogram`; 0 skips it.
+    pub histogram_bins: usize,
+    /// Split iterations across threads in chunks seeded `seed ^ chunk_index`.
+    /// Only honored when built with the `parallel` feature.
+    #[serde(default)]
+    pub parallel: bool,
 }
 
 impl Default for MonteCarloConfig {
@@ -92,11 +728,19 @@ impl Default for MonteCarloConfig {
             iterations: 10000,
             seed: None,
             confidence_level: 0.95,
+            detect_modality: false,
+            track_contributions: false,
+            failure_thresholds: Vec::new(),
+            regime_priors: Vec::new(),
+            correlation_matrix: None,
+            histogram_bins: 20,
+            parallel: false,
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MonteCarloResult {
     pub mean_score: f64,
     pub std_dev: f64,
@@ -109,11 +753,55 @@ pub struct MonteCarloResult {
     pub percentile_95: f64,
     pub confidence_interval: ConfidenceInterval,
     pub risk_of_failure: f64,
+    /// Points lost from `base_score` at the `confidence_level` quantile, e.g.
+    /// the 5th-percentile outcome at 95%.
+    pub value_at_risk: f64,
+    /// Mean points lost across the tail beyond `value_at_risk`; never less severe.
+    pub conditional_var: f64,
     pub iterations_run: usize,
     pub scenario_distribution: Vec<ScenarioOutcome>,
+    /// Normalized entropy of `scenario_distribution`: 0 = one tier, 1 = even spread.
+    pub scenario_diversity: f64,
+    /// Present when `detect_modality` is enabled.
+    pub modality: Option<Modality>,
+    /// `histogram_bins` equal-width bins from `min_score` to `max_score`,
+    /// for charting; the top edge is included in the last bin.
+    pub histogram: Vec<HistogramBin>,
+    /// Per-risk loss breakdown; empty unless `track_contributions` is enabled.
+    pub risk_contributions: Vec<RiskContribution>,
+    /// `(threshold, P(score < threshold))`, ascending by threshold.
+    pub failure_curve: Vec<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HistogramBin {
+    pub range_start: f64,
+    pub range_end: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RiskContribution {
+    pub name: String,
+    pub category: RiskCategory,
+    /// Mean score points lost to this risk per iteration (0 for opportunities).
+    pub expected_loss: f64,
+    /// Fraction of the total expected loss across all risks.
+    pub share: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Modality {
+    Unimodal,
+    Bimodal,  // e.g. success-or-disaster; the mean is misleading
+    Multimodal,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ScenarioOutcome {
     pub scenario_name: String,
     pub probability: f64,
@@ -128,9 +816,67 @@ pub struct RiskFactor {
     pub impact_low: f64,
     pub impact_high: f64,
     pub category: RiskCategory,
+    /// Shape of the impact when the risk hits; defaults to uniform over
+    /// `impact_low..impact_high`.
+    #[serde(default)]
+    pub impact_distribution: ImpactDistribution,
+    #[serde(default)]
+    pub direction: RiskDirection,
+}
+
+/// Whether a materialized risk costs points or (as an upside event) adds them.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum RiskDirection {
+    #[default]
+    Threat,
+    Opportunity,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub enum ImpactDistribution {
+    #[default]
+    Uniform,
+    /// Peaked at `mode`, bounded by `impact_low` and `impact_high`.
+    Triangular { mode: f64 },
+    /// Unbounded bell curve; `impact_low`/`impact_high` are ignored and
+    /// negative draws are treated as no impact.
+    Normal { mean: f64, std: f64 },
+}
+
+/// Analytic risk-count statistics (Poisson-binomial, no sampling).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskMaterialization {
+    pub expected_count: f64,
+    pub variance: f64,
+    pub probability_none: f64,
+}
+
+/// Smallest probability cut on the dominant risk that meets a failure target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mitigation {
+    pub risk_name: String,
+    pub original_probability: f64,
+    pub required_probability: f64,
+    /// `original_probability - required_probability`
+    pub reduction: f64,
+    pub resulting_failure_risk: f64,
+}
+
+/// Change in simulated outcomes from adding one risk (after - before).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskDelta {
+    pub risk_name: String,
+    pub mean_score_delta: f64,
+    pub risk_of_failure_delta: f64,
+    /// `MonteCarloResult::conditional_var` (tail loss below the base score
+    /// at `confidence_level`), before and after.
+    pub cvar_before: f64,
+    pub cvar_after: f64,
+    pub cvar_delta: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum RiskCategory {
     Technical,
     Market,
@@ -163,10 +909,24 @@ pub struct SensitivityVariable {
 pub struct SensitivityResult {
     pub variable_impacts: Vec<VariableImpact>,
     pub tornado_chart_data: Vec<TornadoBar>,
+    /// One score curve per variable across its full range, in input order.
+    #[serde(default)]
+    pub spider_data: Vec<SpiderLine>,
     pub critical_variables: Vec<String>,
     pub recommendations: Vec<String>,
 }
 
+/// Score grid over two variables' ranges, for a heatmap:
+/// `scores[i][j]` is the score at `a_values[i]` and `b_values[j]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwoWaySensitivityResult {
+    pub variable_a: String,
+    pub variable_b: String,
+    pub a_values: Vec<f64>,
+    pub b_values: Vec<f64>,
+    pub scores: Vec<Vec<f64>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VariableImpact {
     pub variable_name: String,
@@ -176,6 +936,17 @@ pub struct VariableImpact {
     pub score_at_max: f64,
     pub score_range: f64,
     pub is_critical: bool,
+    /// Value at which the score drops below 60 when moving from the base
+    /// value toward the unfavorable end; `None` if it never does.
+    #[serde(default)]
+    pub break_even_value: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpiderLine {
+    pub variable_name: String,
+    /// `(percent change from base_value, score)` at each step.
+    pub points: Vec<(f64, f64)>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -186,6 +957,9 @@ pub struct TornadoBar {
     pub base_value: f64,
     pub low_score: f64,
     pub high_score: f64,
+    /// This bar's share of the summed score ranges (0.0-1.0); bars sum to 1.0.
+    #[serde(default)]
+    pub contribution_pct: f64,
 }
 
 // ============================================================================
@@ -193,19 +967,59 @@ pub struct TornadoBar {
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DecisionDecayConfig {
     pub initial_confidence: f64,
     pub decay_factors: Vec<DecayFactor>,
     pub time_horizon_days: u32,
+    /// ISO date (`YYYY-MM-DD...`) the decision was made; needed for debt
+    /// tracking and for a calendar `critical_review_date`.
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Largest stated/computed half-life ratio (either direction) accepted
+    /// by `validate_half_life_claim`.
+    #[serde(default = "default_half_life_tolerance")]
+    pub half_life_tolerance: f64,
+    /// Known future events that knock confidence down in one step.
+    #[serde(default)]
+    pub shock_events: Vec<ShockEvent>,
+    #[serde(default)]
+    pub decay_model: DecayModel,
+}
+
+fn default_half_life_tolerance() -> f64 {
+    2.0
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DecayFactor {
     pub name: String,
     pub decay_rate: f64,
     pub volatility: f64,
 }
 
+/// Shape of the confidence curve. `Exponential` and `Linear` use the
+/// averaged `decay_rate` (% per day); `Sigmoid` holds near the initial
+/// confidence, then collapses around `midpoint_day`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DecayModel {
+    #[default]
+    Exponential,
+    Linear,
+    Sigmoid { midpoint_day: u32, steepness: f64 },
+}
+
+/// A step drop in confidence on `day` (a contract renewal, an election).
+/// `confidence_drop` is the fraction lost, e.g. 0.3 for 30%.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ShockEvent {
+    pub day: u32,
+    pub confidence_drop: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DecisionDecayResult {
     pub half_life_days: f64,
@@ -232,945 +1046,6732 @@ pub enum DecayClassification {
     Critical,     // Half-life < 14 days
 }
 
+/// Score multipliers per decay class for `durability_adjusted_score`: a
+/// decision that needs revisiting within two weeks keeps half its value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DurabilityDiscounts {
+    pub stable: f64,
+    pub moderate: f64,
+    pub volatile: f64,
+    pub critical: f64,
+}
+
+impl Default for DurabilityDiscounts {
+    fn default() -> Self {
+        Self {
+            stable: 0.98,
+            moderate: 0.9,
+            volatile: 0.75,
+            critical: 0.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionDebt {
+    pub days_since_decision: i64,
+    pub critical_review_day: f64,
+    /// Days past the critical review point (0 if not yet due).
+    pub days_overdue: f64,
+    /// Confidence lost since the review was due.
+    pub confidence_erosion: f64,
+    pub level: DebtLevel,
+}
+
+/// Why `decision_debt` could not be computed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecisionDebtError {
+    MissingStartDate,
+    /// Not a valid `YYYY-MM-DD` date.
+    InvalidDate(String),
+}
+
+impl std::fmt::Display for DecisionDebtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecisionDebtError::MissingStartDate => write!(f, "decision has no start_date"),
+            DecisionDebtError::InvalidDate(date) => write!(f, "invalid date: {}", date),
+        }
+    }
+}
+
+impl std::error::Error for DecisionDebtError {}
+
+/// A HALF-LIFE claim that disagrees with the decay model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HalfLifeMismatch {
+    pub stated_days: f64,
+    pub computed_days: f64,
+    /// `max / min` of the two, so always >= 1.
+    pub ratio: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum DebtLevel {
+    None,   // Review not yet due
+    Low,    // Overdue, but still above half confidence
+    High,   // Overdue past the half-life
+}
+
+// ============================================================================
+// CALIBRATION TYPES
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Grade {
+    A, // 85-100
+    B, // 70-84
+    C, // 55-69
+    D, // < 55
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Agreement {
+    pub sample_count: usize,
+    pub mean_absolute_error: f64,
+    pub pearson_correlation: f64,
+    pub cohens_kappa: f64,
+}
+
+// ============================================================================
+// EXPORT TYPES
+// ============================================================================
+
+/// Bumped whenever the `AnalysisBundle` layout changes.
+pub const BUNDLE_SCHEMA_VERSION: u32 = 1;
+
+/// Stored artifact for a full pipeline run; absent analyses are omitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisBundle {
+    pub schema_version: u32,
+    /// UTC `YYYY-MM-DDTHH:MM:SSZ`; `None` where no system clock is available (WASM).
+    pub generated_at: Option<String>,
+    pub score: ScoreResult,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monte_carlo: Option<MonteCarloResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sensitivity: Option<SensitivityResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decay: Option<DecisionDecayResult>,
+}
+
 // ============================================================================
 // MAIN SCORING FUNCTION
 // ============================================================================
 
 /// Main entry used by the WASM wrapper.
 /// Deterministic validator/scorer for decision-grade report template.
+/// Panics on a config whose headers can't be matched; see `try_score_report_text`.
 pub fn score_report_text(input: &str, cfg: ScoringConfig) -> ScoreResult {
-    let cleaned = clean_model_text(input);
-    let norm = normalize_for_headers(&cleaned);
+    try_score_report_text(input, cfg).unwrap_or_else(|e| panic!("{}", e))
+}
 
-    let (missing_headers, duplicate_headers, empty_sections) =
-        evaluate_headers(&norm, &cfg.required_headers);
+/// Like `score_report_text`, but reports a malformed config as an error.
+pub fn try_score_report_text(input: &str, cfg: ScoringConfig) -> Result<ScoreResult, ScoreError> {
+    validate_required_headers(&cfg.required_headers)?;
+    score_cleaned_text(clean_model_text(input), cfg, None)
+}
 
-    let next_actions_count = count_next_actions(&norm);
-    let next_actions_ok = next_actions_count >= cfg.min_next_actions;
+/// `score_report_text` over a reader. Lines are cleaned and scanned for
+/// headers as they arrive, and the header checks, section bodies and
+/// per-section metrics all come from that one pass. The cleaned text is
+/// still kept in full: the document-wide quality metrics, language
+/// detection, truncation and exit-plan checks read it, and some of them take
+/// lower-cased copies, so memory still grows with the report.
+/// Panics on a read error or a malformed config; see `try_score_report_reader`.
+pub fn score_report_reader<R: std::io::BufRead>(reader: R, cfg: ScoringConfig) -> ScoreResult {
+    try_score_report_reader(reader, cfg).unwrap_or_else(|e| panic!("{}", e))
+}
 
-    let truncation_suspected = looks_truncated(&cleaned);
+/// Like `score_report_reader`, but returns read errors (including invalid
+/// UTF-8) and malformed configs instead of panicking.
+pub fn try_score_report_reader<R: std::io::BufRead>(
+    reader: R,
+    cfg: ScoringConfig,
+) -> Result<ScoreResult, ScoreError> {
+    validate_required_headers(&cfg.required_headers)?;
+
+    // Fuzzy matching and quote stripping rewrite lines first, so those scan later
+    let mut scanner = if cfg.header_match_mode == HeaderMatchMode::Exact && !cfg.ignore_quoted_headers {
+        Some(HeaderScanner::new(&cfg.required_headers)?)
+    } else {
+        None
+    };
+    let mut cleaned = String::new();
+    for line in reader.lines() {
+        let line = clean_line(&line?);
+        if let Some(scanner) = scanner.as_mut() {
+            scanner.push(&line);
+        }
+        push_cleaned_line(&mut cleaned, &line);
+    }
+    cleaned.truncate(cleaned.trim_end().len());
 
-    // Scoring: start at 100, subtract penalties deterministically.
-    let mut score: i32 = 100;
-    let mut notes: Vec<String> = Vec::new();
+    score_cleaned_text(cleaned, cfg, scanner.map(HeaderScanner::finish))
+}
+
+/// Headers that can never match a header line (blank or multi-line).
+fn validate_required_headers(headers: &[String]) -> Result<(), ScoreError> {
+    match headers.iter().find(|h| h.trim().is_empty() || h.contains('\n')) {
+        Some(h) => Err(ScoreError::InvalidHeader(h.clone())),
+        None => Ok(()),
+    }
+}
+
+/// Scoring after cleaning; `scan` is the header scan of `cleaned` when the
+/// caller already made one.
+fn score_cleaned_text(
+    cleaned: String,
+    cfg: ScoringConfig,
+    scan: Option<HeaderScan>,
+) -> Result<ScoreResult, ScoreError> {
+    let (cleaned, header_match_notes) = match cfg.header_match_mode {
+        HeaderMatchMode::Exact => (cleaned, Vec::new()),
+        HeaderMatchMode::Fuzzy(max) => fuzzy_match_headers(&cleaned, &cfg.required_headers, max as usize)?,
+    };
+    let scan = match scan {
+        Some(scan) => scan,
+        None if cfg.ignore_quoted_headers => {
+            scan_headers(strip_quoted_lines(&cleaned).lines(), &cfg.required_headers)?
+        }
+        None => scan_headers(cleaned.lines(), &cfg.required_headers)?,
+    };
+
+    let (mut missing_headers, duplicate_headers, empty_sections, thin_sections) =
+        evaluate_headers(&scan, cfg.min_section_words);
+
+    let misordered_headers = if cfg.enforce_header_order {
+        misordered_headers(&scan)
+    } else {
+        Vec::new()
+    };
+
+    let mut deferred_headers: Vec<String> = Vec::new();
+    if cfg.draft_mode {
+        let is_allowed = |h: &String| cfg.allowed_missing.iter().any(|a| a.eq_ignore_ascii_case(h));
+        deferred_headers = missing_headers.iter().filter(|h| is_allowed(h)).cloned().collect();
+        missing_headers.retain(|h| !is_allowed(h));
+    }
+
+    // Bodies as the scan saw them, so ignored (quoted) headers don't count here either
+    let sections: HashMap<String, String> = scan
+        .required
+        .iter()
+        .zip(&scan.first_line)
+        .zip(&scan.bodies)
+        .filter(|((_, line), _)| line.is_some())
+        .map(|((h, _), body)| (h.clone(), body.text.trim().to_string()))
+        .collect();
+    let contradictions = detect_contradictions(&sections);
+
+    let lexicon = quality_lexicon(&cfg, &cleaned);
+    let next_action_quality = sections
+        .get("NEXT ACTIONS")
+        .map(|body| next_action_quality(body, &lexicon.action_verbs))
+        .unwrap_or_default();
+
+    let placeholder_sections: Vec<String> = cfg
+        .required_headers
+        .iter()
+        .filter(|h| !empty_sections.contains(h))
+        .filter(|h| sections.get(*h).is_some_and(|body| !detect_placeholders(body).is_empty()))
+        .cloned()
+        .collect();
+
+    // Padding the list with restated items doesn't earn credit
+    let action_items: Vec<String> = next_action_quality.iter().map(|a| a.action.clone()).collect();
+    let duplicate_next_actions = duplicate_items(&action_items, &lexicon.stopwords);
+    let next_actions_count =
+        count_next_actions(&scan).saturating_sub(duplicate_next_actions.len());
+    let min_next_actions = cfg.effective_min_next_actions(&cleaned);
+    // A deferred NEXT ACTIONS section has nothing to count yet
+    let next_actions_deferred = deferred_headers.iter().any(|h| h.eq_ignore_ascii_case("NEXT ACTIONS"));
+    let next_actions_ok = next_actions_deferred || next_actions_count >= min_next_actions;
+
+    let truncation_suspected = looks_truncated(&cleaned, cfg.check_sentence_completeness);
+
+    // Scoring: start at 100, subtract penalties deterministically.
+    let mut score: i32 = 100;
+    let mut notes: Vec<String> = header_match_notes;
+    let mut penalties: Vec<Penalty> = Vec::new();
+    let weights = &cfg.penalty_weights;
+
+    if !duplicate_next_actions.is_empty() {
+        notes.push(format!(
+            "{} duplicate NEXT ACTIONS not counted",
+            duplicate_next_actions.len()
+        ));
+    }
 
     if !missing_headers.is_empty() {
-        let p = (missing_headers.len() as i32) * 12;
+        let p: i32 = missing_headers
+            .iter()
+            .map(|h| *cfg.header_weights.get(h).unwrap_or(&weights.missing_header) as i32)
+            .sum();
         score -= p;
         notes.push(format!("Missing headers penalty: -{}", p));
+        penalties.push(Penalty { label: "Missing headers".to_string(), points: p as u32 });
     }
 
     if !empty_sections.is_empty() {
-        let p = (empty_sections.len() as i32) * 8;
+        let p = (empty_sections.len() as i32) * weights.empty_section as i32;
         score -= p;
         notes.push(format!("Empty sections penalty: -{}", p));
+        penalties.push(Penalty { label: "Empty sections".to_string(), points: p as u32 });
+    }
+
+    if !thin_sections.is_empty() {
+        let p = (thin_sections.len() as i32) * weights.empty_section as i32;
+        score -= p;
+        notes.push(format!("Thin sections penalty: -{}", p));
+        penalties.push(Penalty { label: "Thin sections".to_string(), points: p as u32 });
     }
 
     if !duplicate_headers.is_empty() {
-        let p = (duplicate_headers.len() as i32) * 6;
+        let p = (duplicate_headers.len() as i32) * weights.duplicate_header as i32;
         score -= p;
         notes.push(format!("Duplicate headers penalty: -{}", p));
+        penalties.push(Penalty { label: "Duplicate headers".to_string(), points: p as u32 });
+    }
+
+    if !misordered_headers.is_empty() {
+        let p = (misordered_headers.len() as i32) * weights.misordered_header as i32;
+        score -= p;
+        notes.push(format!("Misordered headers penalty: -{}", p));
+        penalties.push(Penalty { label: "Misordered headers".to_string(), points: p as u32 });
+    }
+
+    if !placeholder_sections.is_empty() && weights.placeholder_section > 0 {
+        let p = (placeholder_sections.len() as i32) * weights.placeholder_section as i32;
+        score -= p;
+        notes.push(format!("Placeholder sections penalty: -{}", p));
+        penalties.push(Penalty { label: "Placeholder sections".to_string(), points: p as u32 });
+    }
+
+    if !contradictions.is_empty() && weights.contradiction > 0 {
+        let p = (contradictions.len() as i32) * weights.contradiction as i32;
+        score -= p;
+        notes.push(format!("Contradictions penalty: -{}", p));
+        penalties.push(Penalty { label: "Contradictions".to_string(), points: p as u32 });
     }
 
     if !next_actions_ok {
-        let deficit = (cfg.min_next_actions as i32) - (next_actions_count as i32);
-        let p = 10 + (deficit.max(0) * 3);
+        let p = next_actions_penalty(next_actions_count, min_next_actions, weights);
         score -= p;
         notes.push(format!(
             "NEXT ACTIONS count too low ({}), penalty: -{}",
             next_actions_count, p
         ));
+        penalties.push(Penalty { label: "NEXT ACTIONS count too low".to_string(), points: p as u32 });
+    }
+
+    let unsmart_actions = next_action_quality.iter().filter(|a| !a.has_owner || !a.has_deadline).count();
+    if cfg.require_smart_actions && unsmart_actions > 0 && weights.unsmart_action > 0 {
+        let p = (unsmart_actions as i32) * weights.unsmart_action as i32;
+        score -= p;
+        notes.push(format!("Actions missing owner or deadline penalty: -{}", p));
+        penalties.push(Penalty { label: "Actions missing owner or deadline".to_string(), points: p as u32 });
     }
 
     if truncation_suspected {
-        score -= 12;
-        notes.push("Truncation suspected penalty: -12".to_string());
+        let p = weights.truncation as i32;
+        score -= p;
+        notes.push(format!("Truncation suspected penalty: -{}", p));
+        penalties.push(Penalty { label: "Truncation suspected".to_string(), points: p as u32 });
     }
 
     score = score.clamp(0, 100);
 
+    let exit_plan_score = calculate_exit_plan_score(&cleaned, &cfg.required_headers)?;
+    let has_exit_plan = exit_plan_score > 0.0;
+    let exit_plan_bonus = (cfg.exit_plan_bonus as f64 * exit_plan_score).round() as i32;
+    let exit_plan_bonus = exit_plan_bonus.min(100 - score);
+    if exit_plan_bonus > 0 {
+        score += exit_plan_bonus;
+        notes.push(format!("Exit plan bonus: +{}", exit_plan_bonus));
+    }
+
+    // Floor: a report with most of its headers is still worth something.
+    let present_headers = cfg.required_headers.len() - missing_headers.len();
+    let floor = cfg.min_floor_score.min(100) as i32;
+    if score < floor && present_headers * 2 >= cfg.required_headers.len() {
+        notes.push(format!("Score floored at {} (raw: {})", floor, score));
+        score = floor;
+    }
+
+    let structural_completeness = if cfg.required_headers.is_empty() {
+        1.0
+    } else {
+        let absent = missing_headers.len() + deferred_headers.len();
+        (cfg.required_headers.len() - absent) as f64 / cfg.required_headers.len() as f64
+    };
+
     // Calculate quality metrics if enabled
     let quality_metrics = if cfg.enable_quality_metrics {
-        calculate_quality_metrics(&cleaned)
+        let mut metrics =
+            calculate_quality_metrics(&cleaned, &lexicon, cfg.run_on_sentence_words, &cfg.quality_weights);
+        // From the scanned body when BEST OPTION is required, else looked up
+        let best_option = match sections.iter().find(|(h, _)| h.eq_ignore_ascii_case("BEST OPTION")) {
+            Some((_, body)) => Some(body.as_str()),
+            None if scan.required.iter().any(|h| h.eq_ignore_ascii_case("BEST OPTION")) => None,
+            None => section_text(&cleaned, "BEST OPTION", &cfg.required_headers)?,
+        };
+        metrics.decisiveness_score = best_option.map(calculate_decisiveness_score).unwrap_or(0.0);
+        metrics
     } else {
         QualityMetrics::default()
     };
 
+    // Per-section readability, so one dense section can't hide in the average
+    let mut section_readability: HashMap<String, f64> = HashMap::new();
+    if cfg.enable_quality_metrics {
+        for ((h, line), body) in scan.required.iter().zip(&scan.first_line).zip(&scan.bodies) {
+            if line.is_some() {
+                section_readability.insert(h.clone(), body.readability(cfg.run_on_sentence_words));
+            }
+        }
+    }
+
+    let composite_score = if cfg.enable_quality_metrics {
+        let w = cfg.structural_weight.clamp(0.0, 1.0);
+        w * score as f64 + (1.0 - w) * quality_metrics.overall_quality * 100.0
+    } else {
+        score as f64
+    };
+
     // Calculate confidence interval
     let confidence_interval = calculate_confidence_interval(score as f64, &quality_metrics);
 
     // Must-repair rule
     let must_repair =
-        !missing_headers.is_empty() || !next_actions_ok || (truncation_suspected && score < 92);
-
-    let finish_reason_hint = if truncation_suspected {
-        "LIKELY_TRUNCATED".to_string()
-    } else if must_repair {
-        "INCOMPLETE_STRUCTURE".to_string()
-    } else {
-        "OK".to_string()
-    };
-
-    ScoreResult {
+        !missing_headers.is_empty() || !next_actions_ok || (truncation_suspected && (score as u32) < cfg.truncation_repair_below);
+
+    let finish_reason_hint = cfg
+        .finish_reason_rules
+        .iter()
+        .find(|rule| match rule.condition {
+            HintCondition::TruncationSuspected => truncation_suspected,
+            HintCondition::MustRepair => must_repair,
+            HintCondition::MissingHeaders => !missing_headers.is_empty(),
+            HintCondition::EmptySections => !empty_sections.is_empty(),
+            HintCondition::DuplicateHeaders => !duplicate_headers.is_empty(),
+            HintCondition::NextActionsShort => !next_actions_ok,
+            HintCondition::ScoreBelow(threshold) => (score as u32) < threshold,
+            HintCondition::Always => true,
+        })
+        .map(|rule| rule.label.clone())
+        .unwrap_or_else(|| "OK".to_string());
+
+    Ok(ScoreResult {
         score: score as u32,
         must_repair,
         finish_reason_hint,
         missing_headers,
         empty_sections,
+        thin_sections,
         duplicate_headers,
+        misordered_headers,
+        placeholder_sections,
+        contradictions,
+        sections,
+        next_action_quality,
+        duplicate_next_actions,
+        deferred_headers,
         next_actions_count,
         next_actions_ok,
+        next_actions_required: min_next_actions,
+        structural_completeness,
+        composite_score,
         truncation_suspected,
+        has_exit_plan,
+        exit_plan_score,
+        exit_plan_bonus: exit_plan_bonus as u32,
         notes,
+        penalties,
         quality_metrics,
+        section_readability,
         confidence_interval,
+    })
+}
+
+/// Heuristic report type from length and section count.
+pub fn classify_report_type(input: &str) -> ReportType {
+    let cleaned = clean_model_text(input);
+    let words = cleaned.split_whitespace().count();
+    let scan = scan_headers(cleaned.lines(), &DEFAULT_HEADERS).expect("default headers compile");
+    let (missing, _, _, _) = evaluate_headers(&scan, 0);
+    let sections = DEFAULT_HEADERS.len() - missing.len();
+
+    if words < 250 && sections <= 4 {
+        ReportType::Lite
+    } else if words > 900 {
+        ReportType::Major
+    } else {
+        ReportType::Standard
     }
 }
 
-// ============================================================================
-// QUALITY METRICS CALCULATION
-// ============================================================================
+/// Single go/no-go verdict from the score, `must_repair`, and (if a simulation
+/// was run) its `risk_of_failure`.
+pub fn recommendation(
+    result: &ScoreResult,
+    cfg: &ScoringConfig,
+    risk_of_failure: Option<f64>,
+) -> Recommendation {
+    let t = &cfg.recommendation_thresholds;
+
+    if result.score < t.revise_min_score {
+        return Recommendation::Reject;
+    }
+    if result.must_repair || risk_of_failure.is_some_and(|p| p > t.revise_failure_risk) {
+        return Recommendation::Revise;
+    }
 
-fn calculate_quality_metrics(text: &str) -> QualityMetrics {
-    let clarity_score = calculate_clarity_score(text);
-    let specificity_score = calculate_specificity_score(text);
-    let actionability_score = calculate_actionability_score(text);
-    let completeness_score = calculate_completeness_score(text);
-    
-    let overall_quality = (clarity_score * 0.25) 
-        + (specificity_score * 0.30) 
-        + (actionability_score * 0.25) 
-        + (completeness_score * 0.20);
+    let risky = risk_of_failure.is_some_and(|p| p > t.caution_failure_risk);
+    if result.score >= t.proceed_min_score && !risky {
+        Recommendation::Proceed
+    } else if result.score >= t.caution_min_score {
+        Recommendation::ProceedWithCaution
+    } else {
+        Recommendation::Revise
+    }
+}
 
-    QualityMetrics {
-        clarity_score,
-        specificity_score,
-        actionability_score,
-        completeness_score,
-        overall_quality,
+/// Starter report that passes `cfg`: every required header with a fill-in
+/// bullet, and `min_next_actions` numbered placeholders under NEXT ACTIONS.
+pub fn minimal_template(cfg: &ScoringConfig) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut headers: Vec<&str> = cfg.required_headers.iter().map(String::as_str).collect();
+    if !headers.contains(&"NEXT ACTIONS") {
+        headers.push("NEXT ACTIONS");
+    }
+
+    for h in headers {
+        out.push(format!("{}:", h));
+        if h == "NEXT ACTIONS" {
+            let actions = cfg.report_type.map_or(cfg.min_next_actions, |t| t.min_next_actions());
+            for i in 1..=actions.max(1) {
+                out.push(format!("{}. [{} next action {} - owner, deadline]", i, TEMPLATE_PROMPT, i));
+            }
+        } else {
+            out.push(format!("- [{} {}]", TEMPLATE_PROMPT, h.to_lowercase()));
+        }
+        out.push(String::new());
     }
+
+    out.join("\n").trim_end().to_string()
 }
 
-fn calculate_clarity_score(text: &str) -> f64 {
-    let words: Vec<&str> = text.split_whitespace().collect();
-    let word_count = words.len() as f64;
-    
-    if word_count == 0.0 {
-        return 0.0;
+/// Compact, grep-friendly one-liner, e.g.
+/// `[72/100 B] missing=BLIND SPOTS actions=4/6 trunc=no` or `[95/100 A] ok`.
+pub fn summary_line(result: &ScoreResult) -> String {
+    let head = format!("[{}/100 {:?}]", result.score, grade_for_score(result.score));
+
+    let clean = result.missing_headers.is_empty()
+        && result.empty_sections.is_empty()
+        && result.duplicate_headers.is_empty()
+        && result.next_actions_ok
+        && !result.truncation_suspected;
+    if clean {
+        return format!("{} ok", head);
     }
 
-    // Sentence count (approximate)
-    let sentence_count = text.matches('.').count() 
-        + text.matches('!').count() 
-        + text.matches('?').count();
-    let sentence_count = (sentence_count as f64).max(1.0);
+    let mut parts = vec![head];
+    if !result.missing_headers.is_empty() {
+        parts.push(format!("missing={}", result.missing_headers.join(",")));
+    }
+    if !result.empty_sections.is_empty() {
+        parts.push(format!("empty={}", result.empty_sections.join(",")));
+    }
+    if !result.duplicate_headers.is_empty() {
+        parts.push(format!("dup={}", result.duplicate_headers.join(",")));
+    }
+    parts.push(format!(
+        "actions={}/{}",
+        result.next_actions_count, result.next_actions_required
+    ));
+    parts.push(format!(
+        "trunc={}",
+        if result.truncation_suspected { "yes" } else { "no" }
+    ));
+
+    parts.join(" ")
+}
 
-    // Average sentence length (lower is clearer, up to a point)
-    let avg_sentence_length = word_count / sentence_count;
-    
-    // Ideal range: 12-20 words per sentence
-    let length_score = if avg_sentence_length < 8.0 {
-        0.6 + (avg_sentence_length / 8.0) * 0.2
-    } else if avg_sentence_length <= 20.0 {
-        0.8 + ((20.0 - avg_sentence_length) / 12.0) * 0.2
-    } else {
-        0.8 - ((avg_sentence_length - 20.0) / 30.0).min(0.4)
+/// Plain-text email: a `Subject:` line, a blank line, then the grade, the
+/// largest penalty, the NEXT ACTIONS gap and a one-line recommendation.
+pub fn email_digest(result: &ScoreResult, cfg: &ScoringConfig) -> String {
+    let grade = grade_for_score(result.score);
+    let verdict = recommendation(result, cfg, None);
+    let (status, advice) = match verdict {
+        Recommendation::Proceed => ("ready to proceed", "Proceed; no repairs needed."),
+        Recommendation::ProceedWithCaution => {
+            ("proceed with caution", "Proceed, but address the top issue first.")
+        }
+        Recommendation::Revise => ("needs revision", "Revise the report before it goes to a decision."),
+        Recommendation::Reject => ("rejected", "Regenerate the report; it is not usable as written."),
     };
 
-    // Check for bullet points and structure (good for clarity)
-    let has_bullets = text.contains("- ") || text.contains("* ") || text.contains("• ");
-    let structure_bonus = if has_bullets { 0.1 } else { 0.0 };
+    // Largest penalty; ties go to the one applied first
+    let top_issue = match result.penalties.iter().rev().max_by_key(|p| p.points) {
+        Some(p) => {
+            let detail = match p.label.as_str() {
+                "Missing headers" => result.missing_headers.join(", "),
+                "Empty sections" => result.empty_sections.join(", "),
+                "Thin sections" => result.thin_sections.join(", "),
+                "Misordered headers" => result.misordered_headers.join(", "),
+                "Duplicate headers" => result.duplicate_headers.join(", "),
+                "Placeholder sections" => result.placeholder_sections.join(", "),
+                "Contradictions" => result.contradictions.join("; "),
+                _ => String::new(),
+            };
+            if detail.is_empty() {
+                format!("{} (-{} points)", p.label, p.points)
+            } else {
+                format!("{} (-{} points): {}", p.label, p.points, detail)
+            }
+        }
+        None => "None".to_string(),
+    };
+
+    let actions = if result.next_actions_ok {
+        format!("{} of {} required", result.next_actions_count, result.next_actions_required)
+    } else {
+        format!(
+            "{} of {} required ({} short)",
+            result.next_actions_count,
+            result.next_actions_required,
+            result.next_actions_required.saturating_sub(result.next_actions_count)
+        )
+    };
 
-    (length_score + structure_bonus).min(1.0)
+    [
+        format!("Subject: Decision report scored {}/100 ({:?}) — {}", result.score, grade, status),
+        String::new(),
+        format!("Grade: {:?} ({}/100)", grade, result.score),
+        format!("Top issue: {}", top_issue),
+        format!("Next actions: {}", actions),
+        format!("Recommendation: {}", advice),
+    ]
+    .join("\n")
 }
 
-fn calculate_specificity_score(text: &str) -> f64 {
-    let lower = text.to_lowercase();
-    
-    // Vague words that reduce specificity
-    let vague_words = [
-        "some", "many", "few", "various", "several", "often", "sometimes",
-        "might", "could", "possibly", "perhaps", "generally", "usually",
-        "significant", "considerable", "substantial"
-    ];
-    
-    // Specific indicators
-    let specific_patterns = [
-        r"\d+%",           // Percentages
-        r"\$[\d,]+",       // Dollar amounts
-        r"\d+ (days?|weeks?|months?|years?)", // Time durations
-        r"\d{4}-\d{2}-\d{2}", // Dates
-        r"Q[1-4] \d{4}",   // Quarters
-        r"\d+:\d+",        // Times
+/// Human-readable Markdown summary: score, a header checklist (default
+/// header order first), the quality metrics table, and the notes.
+pub fn render_markdown(result: &ScoreResult) -> String {
+    let mut out: Vec<String> = vec![
+        "# Decision report score".to_string(),
+        String::new(),
+        format!(
+            "**Score:** {}/100 ({:?}){}",
+            result.score,
+            grade_for_score(result.score),
+            if result.must_repair { " — must repair" } else { "" }
+        ),
+        String::new(),
+        "## Sections".to_string(),
+        String::new(),
     ];
 
-    let words: Vec<&str> = lower.split_whitespace().collect();
-    let word_count = words.len() as f64;
-    
-    if word_count == 0.0 {
-        return 0.0;
+    let mut headers: Vec<&String> = result
+        .sections
+        .keys()
+        .chain(&result.missing_headers)
+        .chain(&result.deferred_headers)
+        .collect();
+    let rank = |h: &str| DEFAULT_HEADERS.iter().position(|d| *d == h).unwrap_or(DEFAULT_HEADERS.len());
+    headers.sort_by(|a, b| rank(a).cmp(&rank(b)).then_with(|| a.cmp(b)));
+    headers.dedup();
+    for h in headers {
+        let line = if result.missing_headers.contains(h) {
+            format!("- [ ] {} (missing)", h)
+        } else if result.deferred_headers.contains(h) {
+            format!("- [ ] {} (deferred)", h)
+        } else if result.empty_sections.contains(h) {
+            format!("- [ ] {} (empty)", h)
+        } else {
+            format!("- [x] {}", h)
+        };
+        out.push(line);
     }
 
-    // Count vague words
-    let vague_count: usize = vague_words.iter()
-        .map(|w| lower.matches(w).count())
-        .sum();
-    
-    let vague_penalty = (vague_count as f64 / word_count * 10.0).min(0.3);
-
-    // Count specific patterns
-    let mut specific_count = 0;
-    for pattern in &specific_patterns {
-        if let Ok(re) = Regex::new(pattern) {
-            specific_count += re.find_iter(text).count();
-        }
+    let m = &result.quality_metrics;
+    out.extend([
+        String::new(),
+        "## Quality metrics".to_string(),
+        String::new(),
+        "| Metric | Value |".to_string(),
+        "|---|---|".to_string(),
+        format!("| Clarity | {:.2} |", m.clarity_score),
+        format!("| Specificity | {:.2} |", m.specificity_score),
+        format!("| Actionability | {:.2} |", m.actionability_score),
+        format!("| Completeness | {:.2} |", m.completeness_score),
+        format!("| Overall | {:.2} |", m.overall_quality),
+    ]);
+
+    if !result.notes.is_empty() {
+        out.extend([String::new(), "## Notes".to_string(), String::new()]);
+        out.extend(result.notes.iter().map(|n| format!("- {}", n)));
     }
-    
-    let specific_bonus = (specific_count as f64 * 0.05).min(0.3);
 
-    (0.7 - vague_penalty + specific_bonus).clamp(0.0, 1.0)
+    out.join("\n")
 }
 
-fn calculate_actionability_score(text: &str) -> f64 {
-    let lower = text.to_lowercase();
-    
-    // Action verbs that indicate actionability
-    let action_verbs = [
-        "implement", "execute", "deploy", "launch", "create", "build",
-        "develop", "establish", "initiate", "complete", "deliver", "achieve",
-        "schedule", "assign", "review", "analyze", "evaluate", "measure",
-        "track", "monitor", "verify", "validate", "test", "approve"
-    ];
-    
-    // Owner indicators
-    let owner_patterns = [
-        "owner:", "assigned to", "responsible:", "lead:", "by:"
-    ];
-    
-    // Timeline indicators
-    let timeline_patterns = [
-        "by", "before", "within", "deadline", "due", "target date"
-    ];
-
-    let words: Vec<&str> = lower.split_whitespace().collect();
-    let word_count = words.len() as f64;
-    
-    if word_count == 0.0 {
-        return 0.0;
+/// Ordered "100 -> final score" steps for a waterfall chart.
+/// Starts at 100, applies each penalty (never dropping below 0) and ends
+/// with an adjustment step if the floor lifted the score.
+pub fn score_waterfall(result: &ScoreResult) -> Vec<WaterfallStep> {
+    let mut running: i32 = 100;
+    let mut steps = vec![WaterfallStep {
+        label: "Start".to_string(),
+        delta: 0,
+        running_total: running,
+    }];
+
+    for penalty in &result.penalties {
+        let next = (running - penalty.points as i32).max(0);
+        steps.push(WaterfallStep {
+            label: penalty.label.clone(),
+            delta: running - next,
+            running_total: next,
+        });
+        running = next;
     }
 
-    // Count action verbs
-    let action_count: usize = action_verbs.iter()
-        .map(|w| lower.matches(w).count())
-        .sum();
-    
-    let action_score = (action_count as f64 * 0.1).min(0.4);
-
-    // Check for owners
-    let has_owners = owner_patterns.iter().any(|p| lower.contains(p));
-    let owner_bonus = if has_owners { 0.2 } else { 0.0 };
+    if result.exit_plan_bonus > 0 {
+        let next = running + result.exit_plan_bonus as i32;
+        steps.push(WaterfallStep {
+            label: "Exit plan bonus".to_string(),
+            delta: running - next,
+            running_total: next,
+        });
+        running = next;
+    }
 
-    // Check for timelines
-    let has_timelines = timeline_patterns.iter().any(|p| lower.contains(p));
-    let timeline_bonus = if has_timelines { 0.2 } else { 0.0 };
+    let final_score = result.score as i32;
+    if running != final_score {
+        steps.push(WaterfallStep {
+            label: "Score floor".to_string(),
+            delta: running - final_score,
+            running_total: final_score,
+        });
+    }
 
-    (0.2 + action_score + owner_bonus + timeline_bonus).min(1.0)
+    steps
 }
 
-fn calculate_completeness_score(text: &str) -> f64 {
-    let upper = text.to_uppercase();
-    
-    // Check for key sections
-    let key_sections = [
-        ("BEST OPTION", 0.15),
-        ("RATIONALE", 0.15),
-        ("RISKS", 0.15),
-        ("ASSUMPTIONS", 0.15),
-        ("HALF-LIFE", 0.10),
-        ("BLIND SPOTS", 0.10),
-        ("NEXT ACTIONS", 0.20),
-    ];
+/// Signed components that sum exactly to `result.score`: the 100-point base
+/// split across `cfg.required_headers`, each penalty as a negative entry,
+/// and a "Clamp adjustment" for whatever clamping or the floor changed.
+pub fn score_contributions(result: &ScoreResult, cfg: &ScoringConfig) -> Vec<Contribution> {
+    let mut contributions: Vec<Contribution> = Vec::new();
 
-    let mut score = 0.0;
-    for (section, weight) in &key_sections {
-        if upper.contains(section) {
-            score += weight;
+    let n = cfg.required_headers.len() as i32;
+    if n == 0 {
+        contributions.push(Contribution { label: "Base".to_string(), points: 100 });
+    } else {
+        // Spread the remainder over the first headers so credits sum to 100
+        for (i, h) in cfg.required_headers.iter().enumerate() {
+            let points = 100 / n + if (i as i32) < 100 % n { 1 } else { 0 };
+            contributions.push(Contribution { label: h.to_string(), points });
         }
     }
 
-    score
-}
+    for penalty in &result.penalties {
+        contributions.push(Contribution {
+            label: penalty.label.clone(),
+            points: -(penalty.points as i32),
+        });
+    }
 
-fn calculate_confidence_interval(score: f64, metrics: &QualityMetrics) -> ConfidenceInterval {
-    // Use quality metrics to determine confidence interval width
-    let uncertainty = 1.0 - metrics.overall_quality;
-    let margin = uncertainty * 15.0; // Max margin of 15 points
-    
-    ConfidenceInterval {
-        lower_bound: (score - margin).max(0.0),
-        upper_bound: (score + margin).min(100.0),
-        confidence_level: 0.95,
+    if result.exit_plan_bonus > 0 {
+        contributions.push(Contribution {
+            label: "Exit plan bonus".to_string(),
+            points: result.exit_plan_bonus as i32,
+        });
+    }
+
+    let sum: i32 = contributions.iter().map(|c| c.points).sum();
+    let adjustment = result.score as i32 - sum;
+    if adjustment != 0 {
+        contributions.push(Contribution {
+            label: "Clamp adjustment".to_string(),
+            points: adjustment,
+        });
     }
+
+    contributions
 }
 
 // ============================================================================
-// MONTE CARLO SIMULATION
+// RUBRIC LOADING
 // ============================================================================
 
-/// Run Monte Carlo simulation for risk assessment
-pub fn run_monte_carlo_simulation(
-    base_score: f64,
-    risks: &[RiskFactor],
-    config: MonteCarloConfig,
-) -> MonteCarloResult {
-    use std::collections::BinaryHeap;
-    use std::cmp::Reverse;
+const RUBRIC_REQUIRED_FIELDS: [&str; 4] =
+    ["headers", "min_next_actions", "structural_weight", "thresholds"];
+
+/// Build a `ScoringConfig` from a rubric JSON document. Fields the rubric
+/// doesn't cover keep their defaults; fields it doesn't know are an error.
+pub fn load_rubric(json: &str) -> Result<ScoringConfig, RubricError> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| RubricError::Malformed(e.to_string()))?;
+    let obj = value
+        .as_object()
+        .ok_or_else(|| RubricError::Malformed("expected a JSON object".to_string()))?;
+    if let Some(field) = RUBRIC_REQUIRED_FIELDS.iter().find(|f| !obj.contains_key(**f)) {
+        return Err(RubricError::MissingField(field.to_string()));
+    }
 
-    let mut results: Vec<f64> = Vec::with_capacity(config.iterations);
-    
-    // Simple LCG random number generator (deterministic if seed provided)
-    let mut rng_state: u64 = config.seed.unwrap_or(12345);
-    let lcg_next = |state: &mut u64| -> f64 {
-        *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
-        (*state as f64) / (u64::MAX as f64)
+    let rubric: Rubric =
+        serde_json::from_value(value).map_err(|e| RubricError::Malformed(e.to_string()))?;
+    let invalid = |field: &str, reason: String| RubricError::InvalidField {
+        field: field.to_string(),
+        reason,
     };
 
-    // Run simulations
-    for _ in 0..config.iterations {
-        let mut sim_score = base_score;
-        
-        for risk in risks {
-            let random_val = lcg_next(&mut rng_state);
-            
-            // Check if risk materializes
-            if random_val < risk.probability {
-                // Risk occurred - apply impact
-                let impact_range = risk.impact_high - risk.impact_low;
-                let impact_val = lcg_next(&mut rng_state);
-                let actual_impact = risk.impact_low + (impact_range * impact_val);
-                sim_score -= actual_impact;
-            }
+    if rubric.headers.is_empty() {
+        return Err(invalid("headers", "at least one header is required".to_string()));
+    }
+    let mut required_headers: Vec<String> = Vec::new();
+    for h in &rubric.headers {
+        let h = h.trim().to_uppercase();
+        if h.is_empty() {
+            return Err(invalid("headers", "header names must not be blank".to_string()));
         }
-        
-        results.push(sim_score.clamp(0.0, 100.0));
+        required_headers.push(h);
     }
 
-    // Sort results for percentile calculation
-    results.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    if !(0.0..=1.0).contains(&rubric.structural_weight) {
+        return Err(invalid("structural_weight", "must be between 0 and 1".to_string()));
+    }
+    if !(0.0..=1.0).contains(&rubric.max_owner_share) {
+        return Err(invalid("max_owner_share", "must be between 0 and 1".to_string()));
+    }
+    if rubric.min_floor_score > 100 {
+        return Err(invalid("min_floor_score", "must be at most 100".to_string()));
+    }
 
-    // Calculate statistics
-    let n = results.len() as f64;
-    let mean_score: f64 = results.iter().sum::<f64>() / n;
-    
-    let variance: f64 = results.iter()
-        .map(|x| (x - mean_score).powi(2))
-        .sum::<f64>() / n;
-    let std_dev = variance.sqrt();
+    let mut header_weights: HashMap<String, u32> = HashMap::new();
+    for (h, points) in &rubric.header_weights {
+        let h = h.trim().to_uppercase();
+        if !required_headers.contains(&h) {
+            return Err(invalid("header_weights", format!("`{}` is not one of `headers`", h)));
+        }
+        header_weights.insert(h, *points);
+    }
 
-    let min_score = results.first().copied().unwrap_or(0.0);
-    let max_score = results.last().copied().unwrap_or(100.0);
+    let q = &rubric.quality_weights;
+    let parts = [q.clarity, q.specificity, q.actionability, q.completeness];
+    if parts.iter().any(|w| !(0.0..=1.0).contains(w)) || (parts.iter().sum::<f64>() - 1.0).abs() > 1e-6 {
+        return Err(invalid(
+            "quality_weights",
+            "weights must be in 0-1 and sum to 1".to_string(),
+        ));
+    }
 
-    // Percentiles
-    let percentile = |p: f64| -> f64 {
-        let idx = ((p / 100.0) * (results.len() - 1) as f64).round() as usize;
-        results.get(idx).copied().unwrap_or(50.0)
-    };
+    let t = &rubric.thresholds;
+    if !(t.proceed_min_score >= t.caution_min_score && t.caution_min_score >= t.revise_min_score) {
+        return Err(invalid(
+            "thresholds",
+            "expected proceed_min_score >= caution_min_score >= revise_min_score".to_string(),
+        ));
+    }
+    if !(0.0..=1.0).contains(&t.caution_failure_risk)
+        || !(0.0..=1.0).contains(&t.revise_failure_risk)
+        || t.caution_failure_risk > t.revise_failure_risk
+    {
+        return Err(invalid(
+            "thresholds",
+            "failure risks must be in 0-1 with caution_failure_risk <= revise_failure_risk"
+                .to_string(),
+        ));
+    }
 
-    let percentile_5 = percentile(5.0);
-    let percentile_25 = percentile(25.0);
-    let percentile_50 = percentile(50.0);
-    let percentile_75 = percentile(75.0);
-    let percentile_95 = percentile(95.0);
+    Ok(ScoringConfig {
+        required_headers,
+        min_next_actions: rubric.min_next_actions,
+        min_floor_score: rubric.min_floor_score,
+        run_on_sentence_words: rubric.run_on_sentence_words,
+        recommendation_thresholds: rubric.thresholds,
+        max_owner_share: rubric.max_owner_share,
+        penalty_weights: rubric.penalty_weights,
+        header_weights,
+        quality_weights: rubric.quality_weights,
+        structural_weight: rubric.structural_weight,
+        ..ScoringConfig::default()
+    })
+}
 
-    // Confidence interval
-    let ci_lower = percentile((1.0 - config.confidence_level) / 2.0 * 100.0);
-    let ci_upper = percentile((1.0 + config.confidence_level) / 2.0 * 100.0);
+// ============================================================================
+// LEXICONS
+// ============================================================================
 
-    // Risk of failure (score < 60)
-    let failure_count = results.iter().filter(|&&s| s < 60.0).count();
-    let risk_of_failure = failure_count as f64 / n;
+fn to_strings(words: &[&str]) -> Vec<String> {
+    words.iter().map(|w| w.to_string()).collect()
+}
 
-    // Scenario distribution
-    let scenario_distribution = categorize_scenarios(&results);
+/// Lexicon for `text` with `cfg`'s word-list overrides applied.
+fn quality_lexicon(cfg: &ScoringConfig, text: &str) -> Cow<'static, Lexicon> {
+    let lexicon = DEFAULT_LEXICONS.detect_or_use(cfg.language.as_deref(), text);
+    if cfg.vague_words.is_none() && cfg.action_verbs.is_none() {
+        return Cow::Borrowed(lexicon);
+    }
 
-    MonteCarloResult {
-        mean_score,
-        std_dev,
-        min_score,
-        max_score,
-        percentile_5,
-        percentile_25,
-        percentile_50,
-        percentile_75,
-        percentile_95,
-        confidence_interval: ConfidenceInterval {
-            lower_bound: ci_lower,
-            upper_bound: ci_upper,
-            confidence_level: config.confidence_level,
-        },
-        risk_of_failure,
-        iterations_run: config.iterations,
-        scenario_distribution,
+    let mut lexicon = lexicon.clone();
+    if let Some(words) = &cfg.vague_words {
+        lexicon.vague_words = words.clone();
+    }
+    if let Some(verbs) = &cfg.action_verbs {
+        lexicon.action_verbs = verbs.clone();
     }
+    Cow::Owned(lexicon)
 }
 
-fn categorize_scenarios(results: &[f64]) -> Vec<ScenarioOutcome> {
-    let n = results.len() as f64;
-    
-    let excellent = results.iter().filter(|&&s| s >= 90.0).count();
-    let good = results.iter().filter(|&&s| s >= 75.0 && s < 90.0).count();
-    let acceptable = results.iter().filter(|&&s| s >= 60.0 && s < 75.0).count();
-    let poor = results.iter().filter(|&&s| s >= 40.0 && s < 60.0).count();
-    let failure = results.iter().filter(|&&s| s < 40.0).count();
-
-    vec![
-        ScenarioOutcome {
-            scenario_name: "Excellent".to_string(),
-            probability: excellent as f64 / n,
-            score_impact: 0.0,
-            description: "Decision achieves all objectives with minimal issues".to_string(),
-        },
-        ScenarioOutcome {
-            scenario_name: "Good".to_string(),
-            probability: good as f64 / n,
-            score_impact: -10.0,
-            description: "Decision succeeds with minor adjustments needed".to_string(),
-        },
-        ScenarioOutcome {
-            scenario_name: "Acceptable".to_string(),
-            probability: acceptable as f64 / n,
-            score_impact: -25.0,
-            description: "Decision achieves basic objectives but with challenges".to_string(),
-        },
-        ScenarioOutcome {
-            scenario_name: "Poor".to_string(),
-            probability: poor as f64 / n,
-            score_impact: -45.0,
-            description: "Decision faces significant obstacles, requires revision".to_string(),
-        },
-        ScenarioOutcome {
-            scenario_name: "Failure".to_string(),
-            probability: failure as f64 / n,
-            score_impact: -70.0,
-            description: "Decision likely to fail without major intervention".to_string(),
-        },
+/// Dates and money say more than a stray clock time.
+fn default_specific_patterns() -> Vec<SpecificPattern> {
+    [
+        (r"\d+%", 0.05),                           // Percentages
+        (r"\$[\d,]+", 0.08),                       // Dollar amounts
+        (r"\d+ (days?|weeks?|months?|years?)", 0.05), // Time durations
+        (r"\d{4}-\d{2}-\d{2}", 0.08),               // Dates
+        (r"Q[1-4] \d{4}", 0.06),                   // Quarters
+        (r"\d+:\d+", 0.02),                        // Times
     ]
+    .iter()
+    .map(|(pattern, weight)| SpecificPattern {
+        pattern: pattern.to_string(),
+        weight: *weight,
+    })
+    .collect()
 }
 
-// ============================================================================
-// SENSITIVITY ANALYSIS
-// ============================================================================
-
-/// Run sensitivity analysis on decision variables
-pub fn run_sensitivity_analysis(
-    base_score: f64,
-    config: SensitivityConfig,
-) -> SensitivityResult {
-    let mut variable_impacts: Vec<VariableImpact> = Vec::new();
-    let mut tornado_chart_data: Vec<TornadoBar> = Vec::new();
+impl Lexicon {
+    pub fn english() -> Self {
+        Self {
+            language: "en".to_string(),
+            vague_words: to_strings(&[
+                "some", "many", "few", "various", "several", "often", "sometimes",
+                "might", "could", "possibly", "perhaps", "generally", "usually",
+                "significant", "considerable", "substantial",
+            ]),
+            action_verbs: to_strings(&[
+                "implement", "execute", "deploy", "launch", "create", "build",
+                "develop", "establish", "initiate", "complete", "deliver", "achieve",
+                "schedule", "assign", "review", "analyze", "evaluate", "measure",
+                "track", "monitor", "verify", "validate", "test", "approve",
+            ]),
+            stopwords: to_strings(&[
+                "the", "and", "of", "to", "is", "in", "that", "for", "with", "this",
+            ]),
+            specific_patterns: default_specific_patterns(),
+        }
+    }
 
-    for var in &config.variables {
-        let step_size = (var.max_value - var.min_value) / config.step_count as f64;
-        let mut scores_at_values: Vec<(f64, f64)> = Vec::new();
+    pub fn spanish() -> Self {
+        Self {
+            language: "es".to_string(),
+            vague_words: to_strings(&[
+                "algunos", "algunas", "muchos", "muchas", "varios", "varias", "pocos",
+                "a menudo", "a veces", "podría", "posiblemente", "quizás", "tal vez",
+                "generalmente", "normalmente", "significativo", "considerable",
+            ]),
+            action_verbs: to_strings(&[
+                "implementar", "ejecutar", "desplegar", "lanzar", "crear", "construir",
+                "desarrollar", "establecer", "iniciar", "completar", "entregar", "lograr",
+                "programar", "asignar", "revisar", "analizar", "evaluar", "medir",
+                "seguir", "monitorear", "verificar", "validar", "probar", "aprobar",
+            ]),
+            stopwords: to_strings(&[
+                "el", "la", "los", "las", "de", "que", "y", "en", "para", "con", "por", "una",
+            ]),
+            specific_patterns: default_specific_patterns(),
+        }
+    }
 
-        // Calculate score at each step
-        for i in 0..=config.step_count {
-            let value = var.min_value + (step_size * i as f64);
-            let delta = (value - var.base_value) / var.base_value;
-            let score_impact = delta * var.weight * 20.0; // Scaled impact
-            let score = (base_score + score_impact).clamp(0.0, 100.0);
-            scores_at_values.push((value, score));
+    pub fn german() -> Self {
+        Self {
+            language: "de".to_string(),
+            vague_words: to_strings(&[
+                "einige", "viele", "wenige", "verschiedene", "mehrere", "oft", "manchmal",
+                "könnte", "möglicherweise", "vielleicht", "generell", "normalerweise",
+                "erheblich", "beträchtlich", "wesentlich",
+            ]),
+            action_verbs: to_strings(&[
+                "implementieren", "umsetzen", "ausführen", "starten", "erstellen", "bauen",
+                "entwickeln", "einrichten", "abschließen", "liefern", "erreichen",
+                "planen", "zuweisen", "prüfen", "analysieren", "bewerten", "messen",
+                "verfolgen", "überwachen", "verifizieren", "validieren", "testen", "genehmigen",
+            ]),
+            stopwords: to_strings(&[
+                "der", "die", "das", "und", "ist", "nicht", "mit", "für", "auf", "den", "ein", "eine",
+            ]),
+            specific_patterns: default_specific_patterns(),
         }
+    }
+}
 
-        // Calculate elasticity (% change in score / % change in variable)
-        let score_at_min = scores_at_values.first().map(|(_, s)| *s).unwrap_or(base_score);
-        let score_at_max = scores_at_values.last().map(|(_, s)| *s).unwrap_or(base_score);
-        let score_range = score_at_max - score_at_min;
-        
-        let pct_change_score = (score_range / base_score) * 100.0;
-        let pct_change_var = ((var.max_value - var.min_value) / var.base_value) * 100.0;
-        let elasticity = if pct_change_var != 0.0 {
-            pct_change_score / pct_change_var
-        } else {
-            0.0
+/// The built-in lexicons, built once and shared by every score.
+static DEFAULT_LEXICONS: LazyLock<LexiconRegistry> = LazyLock::new(LexiconRegistry::default);
+
+impl Default for LexiconRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            lexicons: HashMap::new(),
+            default_language: "en".to_string(),
         };
+        registry.register(Lexicon::english());
+        registry.register(Lexicon::spanish());
+        registry.register(Lexicon::german());
+        registry
+    }
+}
 
-        // Correlation (simplified: positive if high value = high score)
-        let correlation = if score_at_max > score_at_min { 1.0 } else { -1.0 };
+impl LexiconRegistry {
+    /// Add or replace the lexicon for its language code.
+    pub fn register(&mut self, lexicon: Lexicon) {
+        self.lexicons.insert(lexicon.language.to_lowercase(), lexicon);
+    }
 
-        // Is critical if elasticity > 0.5 or score range > 15
-        let is_critical = elasticity.abs() > 0.5 || score_range.abs() > 15.0;
+    pub fn get(&self, language: &str) -> Option<&Lexicon> {
+        self.lexicons.get(&language.to_lowercase())
+    }
 
-        variable_impacts.push(VariableImpact {
-            variable_name: var.name.clone(),
-            elasticity,
-            correlation,
-            score_at_min,
-            score_at_max,
-            score_range,
-            is_critical,
-        });
+    /// Use the supplied language if registered, otherwise guess from the text.
+    /// Falls back to the default (English) lexicon.
+    pub fn detect_or_use(&self, language: Option<&str>, text: &str) -> &Lexicon {
+        if let Some(lexicon) = language.and_then(|l| self.get(l)) {
+            return lexicon;
+        }
 
-        tornado_chart_data.push(TornadoBar {
-            variable_name: var.name.clone(),
-            low_value: var.min_value,
-            high_value: var.max_value,
-            base_value: var.base_value,
-            low_score: score_at_min,
-            high_score: score_at_max,
-        });
+        self.guess_language(text)
+            .and_then(|l| self.get(&l))
+            .or_else(|| self.get(&self.default_language))
+            .expect("default lexicon must be registered")
     }
 
-    // Sort tornado chart by score range (largest first)
-    tornado_chart_data.sort_by(|a, b| {
-        let range_a = (a.high_score - a.low_score).abs();
-        let range_b = (b.high_score - b.low_score).abs();
-        range_b.partial_cmp(&range_a).unwrap_or(std::cmp::Ordering::Equal)
-    });
+    /// Stopword-frequency language guess. Returns `None` when no stopwords match.
+    pub fn guess_language(&self, text: &str) -> Option<String> {
+        let lower = text.to_lowercase();
+        let words: Vec<&str> = lower
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let mut codes: Vec<&String> = self.lexicons.keys().collect();
+        codes.sort();
+
+        let mut best: Option<(&str, usize)> = None;
+        for code in codes {
+            let lexicon = &self.lexicons[code];
+            let hits = words
+                .iter()
+                .filter(|w| lexicon.stopwords.iter().any(|s| s == *w))
+                .count();
+            if hits > 0 && best.is_none_or(|(_, b)| hits > b) {
+                best = Some((code.as_str(), hits));
+            }
+        }
 
-    // Critical variables
-    let critical_variables: Vec<String> = variable_impacts.iter()
-        .filter(|v| v.is_critical)
-        .map(|v| v.variable_name.clone())
-        .collect();
+        best.map(|(code, _)| code.to_string())
+    }
+}
 
-    // Generate recommendations
-    let recommendations = generate_sensitivity_recommendations(&variable_impacts);
+/// Penalty for having `count` NEXT ACTIONS when `min` are required:
+/// `weights.next_actions_base` plus `weights.next_actions_per_deficit` per
+/// missing action.
+fn next_actions_penalty(count: usize, min: usize, weights: &PenaltyWeights) -> i32 {
+    if count >= min {
+        return 0;
+    }
+    let deficit = (min - count) as i32;
+    weights.next_actions_base as i32 + deficit * weights.next_actions_per_deficit as i32
+}
 
-    SensitivityResult {
-        variable_impacts,
-        tornado_chart_data,
-        critical_variables,
-        recommendations,
+/// Score change from adding one more NEXT ACTION at the `current` count.
+/// Returns 0 once `min_next_actions` is already met.
+pub fn next_action_marginal_value(current: usize, cfg: &ScoringConfig) -> i32 {
+    let min = cfg.report_type.map_or(cfg.min_next_actions, |t| t.min_next_actions());
+    let weights = &cfg.penalty_weights;
+    next_actions_penalty(current, min, weights) - next_actions_penalty(current + 1, min, weights)
+}
+
+/// Points lost to the truncation penalty alone (0 when not suspected), so
+/// the UI can say "-12 for truncation - regenerate to recover".
+pub fn truncation_cost(result: &ScoreResult, cfg: &ScoringConfig) -> i32 {
+    if result.truncation_suspected {
+        cfg.penalty_weights.truncation as i32
+    } else {
+        0
     }
 }
 
-fn generate_sensitivity_recommendations(impacts: &[VariableImpact]) -> Vec<String> {
-    let mut recommendations: Vec<String> = Vec::new();
+// ============================================================================
+// INCREMENTAL SCORING
+// ============================================================================
 
-    for impact in impacts {
-        if impact.is_critical {
-            if impact.correlation > 0.0 {
-                recommendations.push(format!(
-                    "Focus on maximizing '{}' - positive correlation with decision success",
-                    impact.variable_name
-                ));
-            } else {
-                recommendations.push(format!(
-                    "Minimize exposure to '{}' - negative correlation with decision success",
-                    impact.variable_name
-                ));
-            }
+/// Accumulates appended text for live editing and re-scores lazily.
+///
+/// Completed lines are cleaned and fed to a running header scan as they
+/// arrive, so a read only scans the unfinished last line, and sections
+/// closed by a later header keep their readability from when they closed.
+/// Header order, duplicates and the document-wide quality metrics still
+/// depend on the whole text and are recomputed on the first read after an
+/// append. Fuzzy matching and quote stripping rewrite lines before the scan,
+/// so with either one every read re-scores the full text. The result always
+/// matches `score_report_text` on the accumulated text.
+#[derive(Debug, Clone)]
+pub struct IncrementalScorer {
+    cfg: ScoringConfig,
+    text: String,
+    /// Cleaned text of every completed line.
+    cleaned: String,
+    /// Scan of `cleaned`; `None` when only a full re-score is exact.
+    scanner: Option<HeaderScanner>,
+    cached: Option<ScoreResult>,
+}
+
+impl IncrementalScorer {
+    pub fn new(cfg: ScoringConfig) -> Self {
+        let scanner = (validate_required_headers(&cfg.required_headers).is_ok()
+            && cfg.header_match_mode == HeaderMatchMode::Exact
+            && !cfg.ignore_quoted_headers)
+            .then(|| HeaderScanner::new(&cfg.required_headers).ok())
+            .flatten()
+            .map(|scanner| scanner.with_readability(cfg.run_on_sentence_words));
+        Self {
+            cfg,
+            text: String::new(),
+            cleaned: String::new(),
+            scanner,
+            cached: None,
         }
+    }
 
-        if impact.elasticity.abs() > 1.0 {
-            recommendations.push(format!(
-                "High sensitivity to '{}' (elasticity: {:.2}) - small changes have large effects",
-                impact.variable_name, impact.elasticity
-            ));
+    pub fn append(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        let done = self.text.rfind('\n').map_or(0, |i| i + 1);
+        self.text.push_str(text);
+        self.cached = None;
+
+        if let Some(scanner) = self.scanner.as_mut() {
+            let end = self.text.rfind('\n').map_or(0, |i| i + 1);
+            for line in self.text[done..end].lines() {
+                let line = clean_line(line);
+                scanner.push(&line);
+                push_cleaned_line(&mut self.cleaned, &line);
+            }
         }
     }
 
-    if recommendations.is_empty() {
-        recommendations.push("Decision appears robust to variable changes".to_string());
+    pub fn text(&self) -> &str {
+        &self.text
     }
 
-    recommendations
+    /// Latest result for the accumulated text.
+    pub fn current(&mut self) -> &ScoreResult {
+        if self.cached.is_none() {
+            let result = match &self.scanner {
+                Some(scanner) => {
+                    let mut scanner = scanner.clone();
+                    let mut cleaned = self.cleaned.clone();
+                    let tail = &self.text[self.text.rfind('\n').map_or(0, |i| i + 1)..];
+                    if !tail.is_empty() {
+                        let line = clean_line(tail);
+                        scanner.push(&line);
+                        push_cleaned_line(&mut cleaned, &line);
+                    }
+                    cleaned.truncate(cleaned.trim_end().len());
+                    score_cleaned_text(cleaned, self.cfg.clone(), Some(scanner.finish()))
+                        .unwrap_or_else(|e| panic!("{}", e))
+                }
+                None => score_report_text(&self.text, self.cfg.clone()),
+            };
+            self.cached = Some(result);
+        }
+        self.cached.as_ref().unwrap()
+    }
 }
 
 // ============================================================================
-// DECISION DECAY ANALYSIS
+// QUALITY METRICS CALCULATION
 // ============================================================================
 
-/// Calculate decision decay and half-life
-pub fn calculate_decision_decay(config: DecisionDecayConfig) -> DecisionDecayResult {
-    let mut confidence_timeline: Vec<ConfidencePoint> = Vec::new();
-    let mut current_confidence = config.initial_confidence;
-    let mut half_life_days: f64 = 0.0;
-    let mut half_life_found = false;
-
-    // Calculate aggregate decay rate
-    let total_decay_rate: f64 = config.decay_factors.iter()
-        .map(|f| f.decay_rate)
-        .sum::<f64>() / config.decay_factors.len() as f64;
+/// Readability, specificity and actionability of `text` under `lexicon`.
+/// No header validation or penalties, though completeness still rewards the
+/// standard section names wherever they appear in the text.
+/// `decisiveness_score` (which needs a BEST OPTION section) stays 0.
+pub fn calculate_quality_metrics(
+    text: &str,
+    lexicon: &Lexicon,
+    run_on_words: usize,
+    weights: &QualityWeights,
+) -> QualityMetrics {
+    let run_on_sentences = count_run_on_sentences(text, run_on_words);
+    let clarity_score = calculate_clarity_score(text, run_on_sentences);
+    let specificity_score = calculate_specificity_score(text, lexicon);
+    let passive_voice_ratio = passive_voice_ratio(text);
+    // "It was decided" hides who acts; "Dana decides" doesn't
+    let actionability_score =
+        calculate_actionability_score(text, &lexicon.action_verbs) * (1.0 - 0.2 * passive_voice_ratio);
+    let completeness_score = calculate_completeness_score(text);
+    let jargon_score = calculate_jargon_score(text);
+    let lexical_diversity = calculate_lexical_diversity(text);
+    
+    let overall_quality = (clarity_score * weights.clarity)
+        + (specificity_score * weights.specificity)
+        + (actionability_score * weights.actionability)
+        + (completeness_score * weights.completeness);
+    // Light touch: repetition costs at most a tenth of the quality
+    let overall_quality = overall_quality * 0.9 + lexical_diversity * 0.1;
 
-    let total_volatility: f64 = config.decay_factors.iter()
-        .map(|f| f.volatility)
-        .sum::<f64>() / config.decay_factors.len() as f64;
+    QualityMetrics {
+        clarity_score,
+        specificity_score,
+        actionability_score,
+        completeness_score,
+        overall_quality,
+        run_on_sentences,
+        jargon_score,
+        decisiveness_score: 0.0,
+        lexical_diversity,
+        passive_voice_ratio,
+    }
+}
 
-    // Generate timeline
-    for day in 0..=config.time_horizon_days {
-        let decay = (-(total_decay_rate * day as f64 / 100.0)).exp();
-        current_confidence = config.initial_confidence * decay;
+/// Quality metrics for arbitrary prose with the default lexicon (detected
+/// from the text) and run-on threshold. Does no structural validation, so
+/// it suits text that isn't a decision report.
+///
+/// ```
+/// let m = score_engine::score_text_quality(
+///     "Dana will migrate billing to the new vendor by March 3, cutting costs by 12%.",
+/// );
+/// assert!(m.specificity_score > 0.0);
+/// assert_eq!(m.decisiveness_score, 0.0);
+/// ```
+pub fn score_text_quality(text: &str) -> QualityMetrics {
+    let cfg = ScoringConfig::default();
+    let cleaned = clean_model_text(text);
+    let lexicon = quality_lexicon(&cfg, &cleaned);
+    calculate_quality_metrics(&cleaned, &lexicon, cfg.run_on_sentence_words, &cfg.quality_weights)
+}
 
-        let volatility_margin = total_volatility * (day as f64).sqrt() / 10.0;
-        
-        confidence_timeline.push(ConfidencePoint {
-            day,
-            confidence: current_confidence,
-            upper_bound: (current_confidence + volatility_margin).min(100.0),
-            lower_bound: (current_confidence - volatility_margin).max(0.0),
-        });
+/// Words per window in `calculate_lexical_diversity`.
+const TTR_WINDOW: usize = 50;
+
+/// Moving-average type-token ratio: unique/total lowercased words in each
+/// 50-word window, averaged, so long reports aren't penalized for length.
+/// Shorter texts use the plain ratio; an empty text scores 0.
+fn calculate_lexical_diversity(text: &str) -> f64 {
+    let tokens: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect();
+    if tokens.is_empty() {
+        return 0.0;
+    }
 
-        // Find half-life
-        if !half_life_found && current_confidence <= config.initial_confidence / 2.0 {
-            half_life_days = day as f64;
-            half_life_found = true;
-        }
+    let ttr = |window: &[String]| {
+        let unique: HashSet<&String> = window.iter().collect();
+        unique.len() as f64 / window.len() as f64
+    };
+    if tokens.len() <= TTR_WINDOW {
+        return ttr(&tokens);
     }
+    let windows = tokens.windows(TTR_WINDOW);
+    let count = windows.len();
+    windows.map(ttr).sum::<f64>() / count as f64
+}
 
-    // If half-life not reached, extrapolate
-    if !half_life_found {
-        half_life_days = (0.693 / (total_decay_rate / 100.0)).abs();
+// Acronyms any cross-functional reader knows
+const COMMON_ACRONYMS: [&str; 22] = [
+    "OK", "US", "UK", "EU", "CEO", "CFO", "CTO", "COO", "HR", "IT", "PR", "AI", "ID",
+    "USD", "EUR", "GBP", "AM", "PM", "FAQ", "ETA", "TBD", "PDF",
+];
+
+/// Density of undefined all-caps acronyms (2-5 letters). Acronyms defined
+/// inline, e.g. "API (Application Programming Interface)" or
+/// "Application Programming Interface (API)", are not counted. Header lines
+/// are skipped.
+fn calculate_jargon_score(text: &str) -> f64 {
+    let header_line = &*HEADER_LINE_RE;
+    let body: String = text
+        .lines()
+        .filter(|l| !header_line.is_match(l.trim()))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let word_count = body.split_whitespace().count();
+    if word_count == 0 {
+        return 1.0;
     }
 
-    // Classify decay
-    let decay_classification = if half_life_days > 180.0 {
-        DecayClassification::Stable
-    } else if half_life_days > 60.0 {
-        DecayClassification::Moderate
-    } else if half_life_days > 14.0 {
-        DecayClassification::Volatile
-    } else {
-        DecayClassification::Critical
-    };
+    let defined_after = &*ACRONYM_DEFINED_AFTER_RE;
+    let defined_before = &*ACRONYM_DEFINED_BEFORE_RE;
+    let defined: Vec<String> = defined_after
+        .captures_iter(&body)
+        .chain(defined_before.captures_iter(&body))
+        .map(|c| c[1].to_string())
+        .collect();
 
-    // Stability score (0-100)
-    let stability_score = (half_life_days / 365.0 * 100.0).min(100.0);
+    let acronym_re = &*ACRONYM_RE;
+    let undefined = acronym_re
+        .captures_iter(&body)
+        .filter(|c| {
+            let a = &c[1];
+            !COMMON_ACRONYMS.contains(&a) && !defined.iter().any(|d| d == a)
+        })
+        .count();
 
-    // Critical review date
-    let critical_review_date = format!("{} days from now", (half_life_days * 0.5).round() as u32);
+    (1.0 - undefined as f64 / word_count as f64 * 10.0).clamp(0.0, 1.0)
+}
 
-    // Recommendations
-    let recommendations = generate_decay_recommendations(&decay_classification, half_life_days);
+// Qualifiers that soften a recommendation
+const QUALIFIERS: [&str; 16] = [
+    "maybe", "perhaps", "possibly", "potentially", "might", "could", "may", "consider",
+    "somewhat", "probably", "arguably", "seems", "hopefully", "tentatively", "ideally",
+    "depends",
+];
+
+// Phrases that state a clear choice
+const COMMITMENT_CUES: [&str; 6] = ["recommend", "we will", "choose", "go with", "adopt", "proceed with"];
+
+fn calculate_decisiveness_score(section: &str) -> f64 {
+    let lower = section.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.is_empty() {
+        return 0.0;
+    }
 
-    DecisionDecayResult {
-        half_life_days,
-        confidence_timeline,
-        critical_review_date,
-        decay_classification,
-        stability_score,
-        recommendations,
+    let qualifiers = words.iter().filter(|w| QUALIFIERS.contains(w)).count();
+    let density = qualifiers as f64 / words.len() as f64;
+    let commitment_bonus = if COMMITMENT_CUES.iter().any(|c| lower.contains(c)) { 0.1 } else { 0.0 };
+
+    // One qualifier in ten words already reads as noncommittal
+    (1.0 - density * 8.0 + commitment_bonus).clamp(0.0, 1.0)
+}
+
+// Phrases that name a way to back out of the decision
+const EXIT_PLAN_CUES: [&str; 9] = [
+    "rollback", "roll back", "exit criteria", "kill switch", "if this fails", "plan b",
+    "fallback", "fall back", "revert",
+];
+
+/// 0.0-1.0 strength of the rollback/exit plan: each distinct cue is worth 0.5
+/// in BLIND SPOTS or NEXT ACTIONS and 0.25 anywhere else.
+fn calculate_exit_plan_score(cleaned: &str, headers: &[String]) -> Result<f64, ScoreError> {
+    let mut focus: Vec<&str> = Vec::new();
+    for h in ["BLIND SPOTS", "NEXT ACTIONS"] {
+        focus.extend(section_text(cleaned, h, headers)?);
     }
+    let focus = focus.join("\n").to_lowercase();
+    let lower = cleaned.to_lowercase();
+
+    let weight: f64 = EXIT_PLAN_CUES
+        .iter()
+        .map(|c| {
+            if focus.contains(c) {
+                0.5
+            } else if lower.contains(c) {
+                0.25
+            } else {
+                0.0
+            }
+        })
+        .sum();
+
+    Ok(weight.min(1.0))
 }
 
-fn generate_decay_recommendations(classification: &DecayClassification, half_life: f64) -> Vec<String> {
-    let mut recs = Vec::new();
+const BE_AUXILIARIES: [&str; 4] = ["was", "were", "been", "being"];
+const IRREGULAR_PARTICIPLES: [&str; 40] = [
+    "done", "made", "given", "taken", "seen", "known", "shown", "written", "chosen", "driven",
+    "built", "sent", "spent", "kept", "held", "left", "lost", "paid", "sold", "told",
+    "found", "brought", "bought", "caught", "taught", "thought", "set", "put", "cut", "run",
+    "begun", "broken", "forgotten", "frozen", "hidden", "spoken", "stolen", "understood", "won", "met",
+];
+
+/// Share of sentences containing "was/were/been/being" followed by a past
+/// participle (an "-ed" word or a common irregular form), allowing one "-ly"
+/// adverb in between: "was approved", "had been quickly rolled back".
+pub fn passive_voice_ratio(text: &str) -> f64 {
+    let sentences: Vec<Vec<String>> = text
+        .split(['.', '!', '?', '\n'])
+        .map(|s| {
+            s.split_whitespace()
+                .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+                .filter(|w| !w.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|words| !words.is_empty())
+        .collect();
+    if sentences.is_empty() {
+        return 0.0;
+    }
 
-    match classification {
-        DecayClassification::Critical => {
-            recs.push("URGENT: Decision has very short validity window".to_string());
-            recs.push(format!("Schedule review within {} days", (half_life * 0.3).round() as u32));
-            recs.push("Consider if decision can be made more stable".to_string());
+    let is_participle =
+        |w: &str| (w.len() > 3 && w.ends_with("ed")) || IRREGULAR_PARTICIPLES.contains(&w);
+    let passive = sentences
+        .iter()
+        .filter(|words| {
+            words.iter().enumerate().any(|(i, w)| {
+                if !BE_AUXILIARIES.contains(&w.as_str()) {
+                    return false;
+                }
+                match words.get(i + 1).map(String::as_str) {
+                    Some(next) if next.ends_with("ly") => words.get(i + 2).is_some_and(|p| is_participle(p)),
+                    Some(next) => is_participle(next),
+                    None => false,
+                }
+            })
+        })
+        .count();
+    passive as f64 / sentences.len() as f64
+}
+
+/// Count sentences (or list lines) with more than `threshold` words.
+fn count_run_on_sentences(text: &str, threshold: usize) -> usize {
+    text.split(['.', '!', '?', '\n'])
+        .filter(|s| s.split_whitespace().count() > threshold)
+        .count()
+}
+
+fn calculate_clarity_score(text: &str, run_on_sentences: usize) -> f64 {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let word_count = words.len() as f64;
+    
+    if word_count == 0.0 {
+        return 0.0;
+    }
+
+    // Sentence count (approximate)
+    let sentence_count = text.matches('.').count() 
+        + text.matches('!').count() 
+        + text.matches('?').count();
+    let sentence_count = (sentence_count as f64).max(1.0);
+
+    // Average sentence length (lower is clearer, up to a point)
+    let avg_sentence_length = word_count / sentence_count;
+    
+    // Ideal range: 12-20 words per sentence
+    let length_score = if avg_sentence_length < 8.0 {
+        0.6 + (avg_sentence_length / 8.0) * 0.2
+    } else if avg_sentence_length <= 20.0 {
+        0.8 + ((20.0 - avg_sentence_length) / 12.0) * 0.2
+    } else {
+        0.8 - ((avg_sentence_length - 20.0) / 30.0).min(0.4)
+    };
+
+    // Check for bullet points and structure (good for clarity)
+    let has_bullets = text.contains("- ") || text.contains("* ") || text.contains("• ");
+    let structure_bonus = if has_bullets { 0.1 } else { 0.0 };
+
+    // A single run-on can hide inside a healthy average
+    let run_on_penalty = (run_on_sentences as f64 * 0.05).min(0.2);
+
+    (length_score + structure_bonus - run_on_penalty).clamp(0.0, 1.0)
+}
+
+fn calculate_specificity_score(text: &str, lexicon: &Lexicon) -> f64 {
+    let lower = text.to_lowercase();
+
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    let word_count = words.len() as f64;
+    
+    if word_count == 0.0 {
+        return 0.0;
+    }
+
+    // Count vague words
+    let vague_count: usize = lexicon.vague_words.iter()
+        .map(|w| lower.matches(w.as_str()).count())
+        .sum();
+    
+    let vague_penalty = (vague_count as f64 / word_count * 10.0).min(0.3);
+
+    // Weighted sum of specific patterns
+    let mut specific_weight = 0.0;
+    for p in &lexicon.specific_patterns {
+        if let Ok(re) = cached_pattern(&p.pattern) {
+            specific_weight += re.find_iter(text).count() as f64 * p.weight;
         }
-        DecayClassification::Volatile => {
-            recs.push("Decision requires frequent monitoring".to_string());
-            recs.push(format!("Plan for review every {} days", (half_life * 0.4).round() as u32));
-            recs.push("Identify key assumptions that drive volatility".to_string());
+    }
+    
+    let specific_bonus = specific_weight.min(0.3);
+
+    (0.7 - vague_penalty + specific_bonus).clamp(0.0, 1.0)
+}
+
+// Owner indicators
+const OWNER_PATTERNS: [&str; 5] = ["owner:", "assigned to", "responsible:", "lead:", "by:"];
+// Timeline indicators
+const TIMELINE_PATTERNS: [&str; 6] = ["by", "before", "within", "deadline", "due", "target date"];
+
+/// Token-overlap (Jaccard) above which two action items count as the same task.
+const DUPLICATE_ACTION_SIMILARITY: f64 = 0.6;
+
+/// Lowercased tokens of `item` without punctuation or `stopwords`.
+fn item_tokens(item: &str, stopwords: &[String]) -> HashSet<String> {
+    item.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty() && !stopwords.iter().any(|s| s == w))
+        .map(str::to_string)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Token overlap (Jaccard, 0-1) of two items under the stopwords of the
+/// lexicon detected from them: the measure NEXT ACTIONS are deduplicated by.
+pub fn item_similarity(a: &str, b: &str) -> f64 {
+    let stopwords = &DEFAULT_LEXICONS.detect_or_use(None, &format!("{}\n{}", a, b)).stopwords;
+    jaccard(&item_tokens(a, stopwords), &item_tokens(b, stopwords))
+}
+
+/// Whether `a` and `b` would be deduplicated as the same item.
+pub fn is_same_item(a: &str, b: &str) -> bool {
+    item_similarity(a, b) >= DUPLICATE_ACTION_SIMILARITY
+}
+
+/// Items that repeat an earlier one: identical once lowercased and stripped
+/// of punctuation, or sharing most of their non-stopword tokens. Items with
+/// placeholders or template prompts are skipped.
+fn duplicate_items(items: &[String], stopwords: &[String]) -> Vec<String> {
+    let token_sets: Vec<HashSet<String>> = items.iter().map(|i| item_tokens(i, stopwords)).collect();
+
+    let mut duplicates = Vec::new();
+    for (i, set) in token_sets.iter().enumerate() {
+        // Unfilled lines differ only in their number, not in substance
+        if items[i].contains(TEMPLATE_PROMPT) || !detect_placeholders(&items[i]).is_empty() {
+            continue;
         }
-        DecayClassification::Moderate => {
-            recs.push("Decision has reasonable stability".to_string());
-            recs.push(format!("Schedule quarterly review (every {} days)", (half_life * 0.5).round() as u32));
+        let repeats = token_sets[..i]
+            .iter()
+            .any(|earlier| jaccard(set, earlier) >= DUPLICATE_ACTION_SIMILARITY);
+        if repeats {
+            duplicates.push(items[i].clone());
         }
-        DecayClassification::Stable => {
-            recs.push("Decision is highly stable".to_string());
-            recs.push("Annual review recommended".to_string());
-            recs.push("Monitor for black swan events that could invalidate assumptions".to_string());
+    }
+    duplicates
+}
+
+/// `calculate_actionability_score`'s verb/owner/timeline cues applied to each
+/// NEXT ACTIONS item. Timeline words must stand alone here ("by", not
+/// "maybe"), and an ISO date counts as a deadline. Table rows use their
+/// owner and due columns.
+fn next_action_quality(section: &str, action_verbs: &[String]) -> Vec<ActionQuality> {
+    let timeline_re = &*TIMELINE_RE;
+    let check = |action: &str, owner: bool, due: bool| {
+        let lower = action.to_lowercase();
+        ActionQuality {
+            action: action.to_string(),
+            has_verb: action_verbs.iter().any(|v| lower.contains(v.as_str())),
+            has_owner: owner || OWNER_PATTERNS.iter().any(|p| lower.contains(p)),
+            has_deadline: due || timeline_re.is_match(&lower),
         }
+    };
+
+    let table = table_actions(section);
+    if table.is_empty() {
+        section_items(section).iter().map(|item| check(item, false, false)).collect()
+    } else {
+        table
+            .iter()
+            .map(|row| check(&row.action, row.owner.is_some(), row.due.is_some()))
+            .collect()
     }
+}
 
-    recs
+fn calculate_actionability_score(text: &str, action_verbs: &[String]) -> f64 {
+    let lower = text.to_lowercase();
+    
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    let word_count = words.len() as f64;
+    
+    if word_count == 0.0 {
+        return 0.0;
+    }
+
+    // Count action verbs
+    let action_count: usize = action_verbs.iter()
+        .map(|w| lower.matches(w.as_str()).count())
+        .sum();
+    
+    let action_score = (action_count as f64 * 0.1).min(0.4);
+
+    // Check for owners
+    let has_owners = OWNER_PATTERNS.iter().any(|p| lower.contains(p));
+    let owner_bonus = if has_owners { 0.2 } else { 0.0 };
+
+    // Check for timelines
+    let has_timelines = TIMELINE_PATTERNS.iter().any(|p| lower.contains(p));
+    let timeline_bonus = if has_timelines { 0.2 } else { 0.0 };
+
+    (0.2 + action_score + owner_bonus + timeline_bonus).min(1.0)
+}
+
+fn calculate_completeness_score(text: &str) -> f64 {
+    // ASCII keys, so no upper-cased copy of the text is needed
+    let mentions = |key: &str| text.as_bytes().windows(key.len()).any(|w| w.eq_ignore_ascii_case(key.as_bytes()));
+
+    // Check for key sections
+    let key_sections = [
+        ("BEST OPTION", 0.15),
+        ("RATIONALE", 0.15),
+        ("RISKS", 0.15),
+        ("ASSUMPTIONS", 0.15),
+        ("HALF-LIFE", 0.10),
+        ("BLIND SPOTS", 0.10),
+        ("NEXT ACTIONS", 0.20),
+    ];
+
+    let mut score = 0.0;
+    for (section, weight) in &key_sections {
+        if mentions(section) {
+            score += weight;
+        }
+    }
+
+    score
+}
+
+fn calculate_confidence_interval(score: f64, metrics: &QualityMetrics) -> ConfidenceInterval {
+    // Use quality metrics to determine confidence interval width
+    let uncertainty = 1.0 - metrics.overall_quality;
+    let margin = uncertainty * 15.0; // Max margin of 15 points
+    
+    ConfidenceInterval {
+        lower_bound: (score - margin).max(0.0),
+        upper_bound: (score + margin).min(100.0),
+        confidence_level: 0.95,
+    }
+}
+
+// ============================================================================
+// MONTE CARLO SIMULATION
+// ============================================================================
+
+/// Per-iteration scores in simulation order (unsorted). With a fixed seed,
+/// samples for different options are paired by iteration index.
+pub fn simulate_scores(base_score: f64, risks: &[RiskFactor], config: &MonteCarloConfig) -> Vec<f64> {
+    simulate(base_score, risks, config).0
+}
+
+/// Iterations per chunk on the parallel path.
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK: usize = 1000;
+
+/// xoshiro256++ (Blackman & Vigna), seeded through SplitMix64 so nearby
+/// seeds still give unrelated streams. Deterministic for a given seed.
+#[derive(Debug, Clone)]
+struct Xoshiro256PlusPlus {
+    s: [u64; 4],
+}
+
+impl Xoshiro256PlusPlus {
+    fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut splitmix = || {
+            x = x.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        };
+        Self { s: [splitmix(), splitmix(), splitmix(), splitmix()] }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Uniform in [0, 1) from the top 53 bits.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Raw simulation: per-iteration scores and total loss attributed to each risk.
+fn simulate(base_score: f64, risks: &[RiskFactor], config: &MonteCarloConfig) -> (Vec<f64>, Vec<f64>) {
+    let seed = config.seed.unwrap_or(12345);
+    let cholesky = config
+        .correlation_matrix
+        .as_ref()
+        .and_then(|m| cholesky_decomposition(m, risks.len()));
+
+    // Chunks are collected in order, so the output only depends on the seed
+    #[cfg(feature = "parallel")]
+    if config.parallel && config.iterations > PARALLEL_CHUNK {
+        use rayon::prelude::*;
+
+        let chunks = config.iterations.div_ceil(PARALLEL_CHUNK);
+        let parts: Vec<(Vec<f64>, Vec<f64>)> = (0..chunks)
+            .into_par_iter()
+            .map(|c| {
+                let n = PARALLEL_CHUNK.min(config.iterations - c * PARALLEL_CHUNK);
+                simulate_chunk(base_score, risks, config, cholesky.as_deref(), seed ^ c as u64, n)
+            })
+            .collect();
+
+        let mut results: Vec<f64> = Vec::with_capacity(config.iterations);
+        let mut losses: Vec<f64> = vec![0.0; risks.len()];
+        for (r, l) in parts {
+            results.extend(r);
+            for (total, loss) in losses.iter_mut().zip(l) {
+                *total += loss;
+            }
+        }
+        return (results, losses);
+    }
+
+    simulate_chunk(base_score, risks, config, cholesky.as_deref(), seed, config.iterations)
+}
+
+/// `iterations` draws from one RNG stream seeded with `seed`.
+fn simulate_chunk(
+    base_score: f64,
+    risks: &[RiskFactor],
+    config: &MonteCarloConfig,
+    cholesky: Option<&[Vec<f64>]>,
+    seed: u64,
+    iterations: usize,
+) -> (Vec<f64>, Vec<f64>) {
+    let mut results: Vec<f64> = Vec::with_capacity(iterations);
+    let mut losses: Vec<f64> = vec![0.0; risks.len()];
+    let mut rng = Xoshiro256PlusPlus::new(seed);
+
+    let prior_total: f64 = config.regime_priors.iter().map(|(p, _)| p.max(0.0)).sum();
+
+    // Run simulations
+    for _ in 0..iterations {
+        let mut sim_score = base_score;
+
+        // Sample this iteration's regime from the priors
+        let multiplier = if prior_total > 0.0 {
+            let mut pick = rng.next_f64() * prior_total;
+            let mut chosen = 1.0;
+            for &(p, m) in &config.regime_priors {
+                chosen = m;
+                pick -= p.max(0.0);
+                if pick < 0.0 {
+                    break;
+                }
+            }
+            chosen
+        } else {
+            1.0
+        };
+        
+        // Correlated occurrence draws: L * z, mapped back to uniforms
+        let correlated: Option<Vec<f64>> = cholesky.map(|l| {
+            let z: Vec<f64> = (0..risks.len())
+                .map(|_| standard_normal(rng.next_f64(), rng.next_f64()))
+                .collect();
+            l.iter()
+                .map(|row| normal_cdf(row.iter().zip(&z).map(|(a, b)| a * b).sum()))
+                .collect()
+        });
+
+        for (i, risk) in risks.iter().enumerate() {
+            let random_val = match &correlated {
+                Some(u) => u[i],
+                None => rng.next_f64(),
+            };
+            
+            // Check if risk materializes
+            if random_val < (risk.probability * multiplier).clamp(0.0, 1.0) {
+                // Risk occurred - apply impact
+                let impact_val = rng.next_f64();
+                // Only Normal takes a second draw, so other shapes keep the seeded sequence
+                let impact_val2 = match risk.impact_distribution {
+                    ImpactDistribution::Normal { .. } => rng.next_f64(),
+                    _ => 0.0,
+                };
+                let actual_impact = sample_impact(risk, impact_val, impact_val2);
+                match risk.direction {
+                    RiskDirection::Threat => {
+                        sim_score -= actual_impact;
+                        losses[i] += actual_impact;
+                    }
+                    RiskDirection::Opportunity => sim_score += actual_impact,
+                }
+            }
+        }
+        
+        results.push(sim_score.clamp(0.0, 100.0));
+    }
+
+    (results, losses)
 }
 
-// ============================================================================
-// TEXT PROCESSING HELPERS
-// ============================================================================
+/// Impact of `risk` from uniform draws (`u2` is only used by `Normal`).
+fn sample_impact(risk: &RiskFactor, u: f64, u2: f64) -> f64 {
+    let (low, high) = (risk.impact_low, risk.impact_high);
+    match risk.impact_distribution {
+        ImpactDistribution::Uniform => low + (high - low) * u,
+        ImpactDistribution::Triangular { mode } => {
+            let range = high - low;
+            if range <= 0.0 {
+                return low;
+            }
+            // Inverse CDF of the triangular distribution
+            let mode = mode.clamp(low, high);
+            let split = (mode - low) / range;
+            if u < split {
+                low + (u * range * (mode - low)).sqrt()
+            } else {
+                high - ((1.0 - u) * range * (high - mode)).sqrt()
+            }
+        }
+        ImpactDistribution::Normal { mean, std } => (mean + std * standard_normal(u, u2)).max(0.0),
+    }
+}
+
+/// Mean of `sample_impact` for `risk` under its distribution.
+fn mean_impact(risk: &RiskFactor) -> f64 {
+    let (low, high) = (risk.impact_low, risk.impact_high);
+    match risk.impact_distribution {
+        ImpactDistribution::Uniform => (low + high) / 2.0,
+        ImpactDistribution::Triangular { .. } if high <= low => low,
+        ImpactDistribution::Triangular { mode } => (low + mode.clamp(low, high) + high) / 3.0,
+        // Negative draws count as 0: mean of the rectified normal
+        ImpactDistribution::Normal { mean, std } if std <= 0.0 => mean.max(0.0),
+        ImpactDistribution::Normal { mean, std } => {
+            let z = mean / std;
+            let density = (-z * z / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt();
+            mean * normal_cdf(z) + std * density
+        }
+    }
+}
+
+/// Lower-triangular `L` with `L * L^T = matrix`, or `None` if `matrix` is
+/// not `n x n` or not positive semi-definite. Zero pivots (perfect
+/// correlation) are allowed.
+fn cholesky_decomposition(matrix: &[Vec<f64>], n: usize) -> Option<Vec<Vec<f64>>> {
+    if matrix.len() != n || matrix.iter().any(|row| row.len() != n) {
+        return None;
+    }
+
+    let mut l = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+            if i == j {
+                let d = matrix[i][i] - sum;
+                if d < -1e-9 {
+                    return None;
+                }
+                l[i][i] = d.max(0.0).sqrt();
+            } else if l[j][j] > 0.0 {
+                l[i][j] = (matrix[i][j] - sum) / l[j][j];
+            }
+        }
+    }
+    Some(l)
+}
+
+/// Box-Muller: one standard-normal draw from two uniforms in [0, 1].
+fn standard_normal(u1: f64, u2: f64) -> f64 {
+    let u1 = (1.0 - u1).max(f64::MIN_POSITIVE);
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Standard normal CDF via the Abramowitz-Stegun erf approximation (|error| < 1.5e-7).
+fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.3275911 * z);
+    let poly = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - poly * (-z * z).exp();
+    if x >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+/// Run Monte Carlo simulation for risk assessment
+pub fn run_monte_carlo_simulation(
+    base_score: f64,
+    risks: &[RiskFactor],
+    config: MonteCarloConfig,
+) -> MonteCarloResult {
+    let (mut results, losses) = simulate(base_score, risks, &config);
+
+    // Sort results for percentile calculation
+    results.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    // Calculate statistics
+    let n = results.len() as f64;
+    let mean_score: f64 = results.iter().sum::<f64>() / n;
+    
+    let variance: f64 = results.iter()
+        .map(|x| (x - mean_score).powi(2))
+        .sum::<f64>() / n;
+    let std_dev = variance.sqrt();
+
+    let min_score = results.first().copied().unwrap_or(0.0);
+    let max_score = results.last().copied().unwrap_or(100.0);
+
+    // Percentiles
+    let percentile = |p: f64| -> f64 {
+        let idx = ((p / 100.0) * (results.len() - 1) as f64).round() as usize;
+        results.get(idx).copied().unwrap_or(50.0)
+    };
+
+    let percentile_5 = percentile(5.0);
+    let percentile_25 = percentile(25.0);
+    let percentile_50 = percentile(50.0);
+    let percentile_75 = percentile(75.0);
+    let percentile_95 = percentile(95.0);
+
+    // Confidence interval
+    let ci_lower = percentile((1.0 - config.confidence_level) / 2.0 * 100.0);
+    let ci_upper = percentile((1.0 + config.confidence_level) / 2.0 * 100.0);
+
+    // Risk of failure (score < 60)
+    let failure_count = results.iter().filter(|&&s| s < 60.0).count();
+    let risk_of_failure = failure_count as f64 / n;
+
+    // Loss measures relative to the starting score
+    let tail = (1.0 - config.confidence_level).clamp(0.0, 1.0);
+    let value_at_risk = base_score - percentile(tail * 100.0);
+    let conditional_var = base_score - conditional_value_at_risk(&results, tail);
+
+    // Scenario distribution
+    let scenario_distribution = categorize_scenarios(&results);
+    let scenario_diversity = scenario_diversity(&scenario_distribution);
+
+    let risk_contributions = if config.track_contributions {
+        let total_loss: f64 = losses.iter().sum();
+        risks
+            .iter()
+            .zip(&losses)
+            .map(|(risk, loss)| RiskContribution {
+                name: risk.name.clone(),
+                category: risk.category.clone(),
+                expected_loss: loss / n,
+                share: if total_loss > 0.0 { loss / total_loss } else { 0.0 },
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let failure_curve = failure_curve(&results, &config.failure_thresholds);
+
+    let histogram = histogram_bins(&results, config.histogram_bins);
+
+    // Modality (results are already sorted)
+    let modality = if config.detect_modality {
+        Some(detect_modality(&results, std_dev))
+    } else {
+        None
+    };
+
+    MonteCarloResult {
+        mean_score,
+        std_dev,
+        min_score,
+        max_score,
+        percentile_5,
+        percentile_25,
+        percentile_50,
+        percentile_75,
+        percentile_95,
+        confidence_interval: ConfidenceInterval {
+            lower_bound: ci_lower,
+            upper_bound: ci_upper,
+            confidence_level: config.confidence_level,
+        },
+        risk_of_failure,
+        value_at_risk,
+        conditional_var,
+        iterations_run: config.iterations,
+        scenario_distribution,
+        scenario_diversity,
+        modality,
+        histogram,
+        risk_contributions,
+        failure_curve,
+    }
+}
+
+/// Equal-width bins over `sorted[0]..=sorted[last]`; one bin when all
+/// outcomes are equal.
+fn histogram_bins(sorted: &[f64], bins: usize) -> Vec<HistogramBin> {
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return Vec::new();
+    };
+    if bins == 0 {
+        return Vec::new();
+    }
+    if max <= min {
+        return vec![HistogramBin { range_start: min, range_end: max, count: sorted.len() }];
+    }
+
+    let width = (max - min) / bins as f64;
+    let mut histogram: Vec<HistogramBin> = (0..bins)
+        .map(|i| HistogramBin {
+            range_start: min + width * i as f64,
+            range_end: if i + 1 == bins { max } else { min + width * (i + 1) as f64 },
+            count: 0,
+        })
+        .collect();
+    for s in sorted {
+        let i = (((s - min) / width) as usize).min(bins - 1);
+        histogram[i].count += 1;
+    }
+    histogram
+}
+
+/// P(score < t) for each threshold, in one pass over the sorted samples.
+fn failure_curve(sorted: &[f64], thresholds: &[f64]) -> Vec<(f64, f64)> {
+    let mut thresholds = thresholds.to_vec();
+    thresholds.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let n = sorted.len().max(1) as f64;
+    let mut below = 0;
+    thresholds
+        .into_iter()
+        .map(|t| {
+            while below < sorted.len() && sorted[below] < t {
+                below += 1;
+            }
+            (t, below as f64 / n)
+        })
+        .collect()
+}
+
+/// Percentile rank (0.0-1.0) of each risk's share of the expected loss:
+/// the dominant risk ranks 1.0, the smallest 0.0.
+/// Requires a result produced with `track_contributions` enabled.
+pub fn risk_contribution_ranks(result: &MonteCarloResult) -> Vec<(String, f64)> {
+    let contributions = &result.risk_contributions;
+    let n = contributions.len();
+    if n == 1 {
+        return vec![(contributions[0].name.clone(), 1.0)];
+    }
+
+    contributions
+        .iter()
+        .map(|c| {
+            let below = contributions.iter().filter(|o| o.share < c.share).count();
+            (c.name.clone(), below as f64 / (n - 1) as f64)
+        })
+        .collect()
+}
+
+/// Fraction of simulated outcomes that fall inside an analytic interval,
+/// read from `mc.histogram`. Partially covered bins are counted
+/// proportionally, so the estimate sharpens with more `histogram_bins`.
+/// Coverage well below `ci.confidence_level` means the interval is too narrow.
+/// `None` when there is no histogram to read (`histogram_bins: 0`).
+pub fn interval_coverage(ci: &ConfidenceInterval, mc: &MonteCarloResult) -> Option<f64> {
+    let total: usize = mc.histogram.iter().map(|b| b.count).sum();
+    if total == 0 {
+        return None;
+    }
+    if ci.upper_bound < ci.lower_bound {
+        return Some(0.0);
+    }
+
+    let covered: f64 = mc
+        .histogram
+        .iter()
+        .map(|bin| {
+            let width = bin.range_end - bin.range_start;
+            let fraction = if width > 0.0 {
+                (bin.range_end.min(ci.upper_bound) - bin.range_start.max(ci.lower_bound)).max(0.0) / width
+            } else if (ci.lower_bound..=ci.upper_bound).contains(&bin.range_start) {
+                // All outcomes equal: the single bin is a point
+                1.0
+            } else {
+                0.0
+            };
+            bin.count as f64 * fraction
+        })
+        .sum();
+
+    Some(covered / total as f64)
+}
+
+/// Count density peaks with a Gaussian KDE over the 0-100 score range.
+/// Bandwidth follows Silverman's rule of thumb; peaks under 10% of the
+/// tallest one are treated as noise.
+fn detect_modality(sorted: &[f64], std_dev: f64) -> Modality {
+    let n = sorted.len() as f64;
+    if sorted.len() < 2 || std_dev == 0.0 {
+        return Modality::Unimodal;
+    }
+
+    let bandwidth = (1.06 * std_dev * n.powf(-0.2)).max(1.0);
+    let density: Vec<f64> = (0..=100)
+        .map(|x| {
+            let x = x as f64;
+            sorted
+                .iter()
+                .map(|s| (-0.5 * ((x - s) / bandwidth).powi(2)).exp())
+                .sum::<f64>()
+        })
+        .collect();
+
+    let tallest = density.iter().cloned().fold(0.0, f64::max);
+    let peaks = (0..density.len())
+        .filter(|&i| {
+            let left = if i > 0 { density[i - 1] } else { f64::MIN };
+            let right = density.get(i + 1).copied().unwrap_or(f64::MIN);
+            density[i] > left && density[i] >= right && density[i] >= tallest * 0.1
+        })
+        .count();
+
+    match peaks {
+        0 | 1 => Modality::Unimodal,
+        2 => Modality::Bimodal,
+        _ => Modality::Multimodal,
+    }
+}
+
+/// Expected number of risks that materialize: `Σ p_i`.
+pub fn expected_risks_materializing(risks: &[RiskFactor]) -> f64 {
+    risks.iter().map(|r| r.probability).sum()
+}
+
+/// Expected count, variance (`Σ p_i(1 - p_i)`) and the chance that no risk
+/// materializes (`Π (1 - p_i)`), assuming independent risks.
+pub fn risk_materialization_stats(risks: &[RiskFactor]) -> RiskMaterialization {
+    RiskMaterialization {
+        expected_count: expected_risks_materializing(risks),
+        variance: risks.iter().map(|r| r.probability * (1.0 - r.probability)).sum(),
+        probability_none: risks.iter().map(|r| 1.0 - r.probability).product(),
+    }
+}
+
+/// Win-probability matrix for N options: `m[i][j]` is the chance option `i`
+/// scores higher than option `j` on the same iteration (ties count half).
+/// Samples are paired by index; the diagonal is 0.5.
+pub fn dominance_matrix(results: &[(String, Vec<f64>)]) -> Vec<Vec<f64>> {
+    let n = results.len();
+    let mut matrix = vec![vec![0.5; n]; n];
+
+    for i in 0..n {
+        for j in 0..n {
+            if i == j {
+                continue;
+            }
+            let (a, b) = (&results[i].1, &results[j].1);
+            let pairs = a.len().min(b.len());
+            if pairs == 0 {
+                continue;
+            }
+            let wins: f64 = a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| if x > y { 1.0 } else if x == y { 0.5 } else { 0.0 })
+                .sum();
+            matrix[i][j] = wins / pairs as f64;
+        }
+    }
+
+    matrix
+}
+
+/// Binary-search the dominant threat's probability (highest probability times
+/// mean sampled impact) for the smallest reduction that brings
+/// `risk_of_failure` to `target_failure`. Opportunities are never candidates:
+/// making an upside less likely only adds failures. Every probe reuses the
+/// same seed. Returns `None` if there is no threat, or if even eliminating it
+/// can't reach the target.
+pub fn required_mitigation(
+    base: f64,
+    risks: &[RiskFactor],
+    target_failure: f64,
+    config: MonteCarloConfig,
+) -> Option<Mitigation> {
+    let config = MonteCarloConfig {
+        seed: Some(config.seed.unwrap_or(12345)),
+        ..config
+    };
+    let expected_impact = |r: &RiskFactor| r.probability * mean_impact(r);
+    let dominant = (0..risks.len())
+        .filter(|&i| risks[i].direction == RiskDirection::Threat)
+        .max_by(|&a, &b| {
+            expected_impact(&risks[a])
+                .partial_cmp(&expected_impact(&risks[b]))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+
+    let failure_at = |p: f64| {
+        let mut adjusted = risks.to_vec();
+        adjusted[dominant].probability = p;
+        run_monte_carlo_simulation(base, &adjusted, config.clone()).risk_of_failure
+    };
+
+    let original = risks[dominant].probability;
+    let mitigation = |p: f64, failure: f64| Mitigation {
+        risk_name: risks[dominant].name.clone(),
+        original_probability: original,
+        required_probability: p,
+        reduction: original - p,
+        resulting_failure_risk: failure,
+    };
+
+    let current = failure_at(original);
+    if current <= target_failure {
+        return Some(mitigation(original, current));
+    }
+    if failure_at(0.0) > target_failure {
+        return None;
+    }
+
+    // Invariant: failure(lo) <= target < failure(hi)
+    let (mut lo, mut hi) = (0.0, original);
+    for _ in 0..30 {
+        let mid = (lo + hi) / 2.0;
+        if failure_at(mid) <= target_failure {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Some(mitigation(lo, failure_at(lo)))
+}
+
+/// Re-run the simulation with `new_risk` added, on the same seed, and
+/// report how the outcome shifts.
+pub fn add_risk_delta(
+    base: f64,
+    existing: &[RiskFactor],
+    new_risk: RiskFactor,
+    config: MonteCarloConfig,
+) -> RiskDelta {
+    let mut extended = existing.to_vec();
+    extended.push(new_risk);
+
+    // One pass per risk set, summarized as `run_monte_carlo_simulation` does:
+    // (mean, risk of failure, conditional VaR)
+    let tail = (1.0 - config.confidence_level).clamp(0.0, 1.0);
+    let summarize = |risks: &[RiskFactor]| {
+        let scores = simulate_scores(base, risks, &config);
+        let n = scores.len().max(1) as f64;
+        let mean = scores.iter().sum::<f64>() / n;
+        let failure = scores.iter().filter(|&&s| s < 60.0).count() as f64 / n;
+        (mean, failure, base - conditional_value_at_risk(&scores, tail))
+    };
+    let (mean_before, failure_before, cvar_before) = summarize(existing);
+    let (mean_after, failure_after, cvar_after) = summarize(&extended);
+
+    RiskDelta {
+        risk_name: extended.last().map(|r| r.name.clone()).unwrap_or_default(),
+        mean_score_delta: mean_after - mean_before,
+        risk_of_failure_delta: failure_after - failure_before,
+        cvar_before,
+        cvar_after,
+        cvar_delta: cvar_after - cvar_before,
+    }
+}
+
+/// Mean score of the worst `tail` fraction of outcomes.
+fn conditional_value_at_risk(scores: &[f64], tail: f64) -> f64 {
+    if scores.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = scores.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let k = ((sorted.len() as f64 * tail).ceil() as usize).clamp(1, sorted.len());
+    sorted[..k].iter().sum::<f64>() / k as f64
+}
+
+fn categorize_scenarios(results: &[f64]) -> Vec<ScenarioOutcome> {
+    let n = results.len() as f64;
+    
+    let excellent = results.iter().filter(|&&s| s >= 90.0).count();
+    let good = results.iter().filter(|&&s| (75.0..90.0).contains(&s)).count();
+    let acceptable = results.iter().filter(|&&s| (60.0..75.0).contains(&s)).count();
+    let poor = results.iter().filter(|&&s| (40.0..60.0).contains(&s)).count();
+    let failure = results.iter().filter(|&&s| s < 40.0).count();
+
+    vec![
+        ScenarioOutcome {
+            scenario_name: "Excellent".to_string(),
+            probability: excellent as f64 / n,
+            score_impact: 0.0,
+            description: "Decision achieves all objectives with minimal issues".to_string(),
+        },
+        ScenarioOutcome {
+            scenario_name: "Good".to_string(),
+            probability: good as f64 / n,
+            score_impact: -10.0,
+            description: "Decision succeeds with minor adjustments needed".to_string(),
+        },
+        ScenarioOutcome {
+            scenario_name: "Acceptable".to_string(),
+            probability: acceptable as f64 / n,
+            score_impact: -25.0,
+            description: "Decision achieves basic objectives but with challenges".to_string(),
+        },
+        ScenarioOutcome {
+            scenario_name: "Poor".to_string(),
+            probability: poor as f64 / n,
+            score_impact: -45.0,
+            description: "Decision faces significant obstacles, requires revision".to_string(),
+        },
+        ScenarioOutcome {
+            scenario_name: "Failure".to_string(),
+            probability: failure as f64 / n,
+            score_impact: -70.0,
+            description: "Decision likely to fail without major intervention".to_string(),
+        },
+    ]
+}
+
+/// Shannon entropy of the tier probabilities divided by its maximum, ln(tiers).
+fn scenario_diversity(scenarios: &[ScenarioOutcome]) -> f64 {
+    if scenarios.len() < 2 {
+        return 0.0;
+    }
+    let entropy: f64 = scenarios
+        .iter()
+        .filter(|s| s.probability > 0.0)
+        .map(|s| -s.probability * s.probability.ln())
+        .sum();
+    (entropy / (scenarios.len() as f64).ln()).clamp(0.0, 1.0)
+}
+
+// ============================================================================
+// SENSITIVITY ANALYSIS
+// ============================================================================
+
+/// Run sensitivity analysis on decision variables
+pub fn run_sensitivity_analysis(
+    base_score: f64,
+    config: SensitivityConfig,
+) -> SensitivityResult {
+    run_sensitivity_analysis_with(base_score, config, |var, value| {
+        base_score + linear_impact(var, value)
+    })
+}
+
+/// `run_sensitivity_analysis` with a caller-supplied model: `score_at(var, value)`
+/// returns the score when `var` is moved to `value` (clamped to 0-100), so
+/// saturating or threshold effects can be expressed.
+pub fn run_sensitivity_analysis_with<F>(
+    base_score: f64,
+    config: SensitivityConfig,
+    score_at: F,
+) -> SensitivityResult
+where
+    F: Fn(&SensitivityVariable, f64) -> f64,
+{
+    let mut variable_impacts: Vec<VariableImpact> = Vec::new();
+    let mut tornado_chart_data: Vec<TornadoBar> = Vec::new();
+    let mut spider_data: Vec<SpiderLine> = Vec::new();
+
+    for var in &config.variables {
+        let step_size = (var.max_value - var.min_value) / config.step_count as f64;
+        let mut scores_at_values: Vec<(f64, f64)> = Vec::new();
+
+        // Calculate score at each step
+        for i in 0..=config.step_count {
+            let value = var.min_value + (step_size * i as f64);
+            let score = score_at(var, value).clamp(0.0, 100.0);
+            scores_at_values.push((value, score));
+        }
+
+        spider_data.push(SpiderLine {
+            variable_name: var.name.clone(),
+            points: scores_at_values
+                .iter()
+                .map(|(value, score)| ((value - var.base_value) / var.base_value * 100.0, *score))
+                .collect(),
+        });
+
+        // Calculate elasticity (% change in score / % change in variable)
+        let score_at_min = scores_at_values.first().map(|(_, s)| *s).unwrap_or(base_score);
+        let score_at_max = scores_at_values.last().map(|(_, s)| *s).unwrap_or(base_score);
+        let score_range = score_at_max - score_at_min;
+        
+        let pct_change_score = (score_range / base_score) * 100.0;
+        let pct_change_var = ((var.max_value - var.min_value) / var.base_value) * 100.0;
+        let elasticity = if pct_change_var != 0.0 {
+            pct_change_score / pct_change_var
+        } else {
+            0.0
+        };
+
+        // Correlation (simplified: positive if high value = high score)
+        let correlation = if score_at_max > score_at_min { 1.0 } else { -1.0 };
+
+        // Is critical if elasticity > 0.5 or score range > 15
+        let is_critical = elasticity.abs() > 0.5 || score_range.abs() > 15.0;
+
+        // Walk from the base value toward the lower-scoring end
+        let base_point = (var.base_value, score_at(var, var.base_value).clamp(0.0, 100.0));
+        let toward_unfavorable: Vec<(f64, f64)> = if score_at_min < score_at_max {
+            std::iter::once(base_point)
+                .chain(scores_at_values.iter().rev().filter(|(v, _)| *v < var.base_value).copied())
+                .collect()
+        } else {
+            std::iter::once(base_point)
+                .chain(scores_at_values.iter().filter(|(v, _)| *v > var.base_value).copied())
+                .collect()
+        };
+        let break_even_value = break_even(&toward_unfavorable, 60.0);
+
+        variable_impacts.push(VariableImpact {
+            variable_name: var.name.clone(),
+            elasticity,
+            correlation,
+            score_at_min,
+            score_at_max,
+            score_range,
+            is_critical,
+            break_even_value,
+        });
+
+        tornado_chart_data.push(TornadoBar {
+            variable_name: var.name.clone(),
+            low_value: var.min_value,
+            high_value: var.max_value,
+            base_value: var.base_value,
+            low_score: score_at_min,
+            high_score: score_at_max,
+            contribution_pct: 0.0,
+        });
+    }
+
+    let total_range: f64 = tornado_chart_data.iter().map(|b| (b.high_score - b.low_score).abs()).sum();
+    if total_range > 0.0 {
+        for bar in &mut tornado_chart_data {
+            bar.contribution_pct = (bar.high_score - bar.low_score).abs() / total_range;
+        }
+    }
+
+    // Sort tornado chart by score range (largest first)
+    tornado_chart_data.sort_by(|a, b| {
+        let range_a = (a.high_score - a.low_score).abs();
+        let range_b = (b.high_score - b.low_score).abs();
+        range_b.partial_cmp(&range_a).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // Critical variables
+    let critical_variables: Vec<String> = variable_impacts.iter()
+        .filter(|v| v.is_critical)
+        .map(|v| v.variable_name.clone())
+        .collect();
+
+    // Generate recommendations
+    let recommendations = generate_sensitivity_recommendations(&variable_impacts);
+
+    SensitivityResult {
+        variable_impacts,
+        tornado_chart_data,
+        spider_data,
+        critical_variables,
+        recommendations,
+    }
+}
+
+/// First value along `path` where the score falls from `>= threshold` to
+/// below it, linearly interpolated between the two straddling points.
+fn break_even(path: &[(f64, f64)], threshold: f64) -> Option<f64> {
+    path.windows(2).find_map(|w| {
+        let ((v0, s0), (v1, s1)) = (w[0], w[1]);
+        (s0 >= threshold && s1 < threshold).then(|| v0 + (v1 - v0) * (s0 - threshold) / (s0 - s1))
+    })
+}
+
+/// Score change from moving `var` to `value`: relative change times weight, scaled.
+fn linear_impact(var: &SensitivityVariable, value: f64) -> f64 {
+    let delta = (value - var.base_value) / var.base_value;
+    delta * var.weight * 20.0
+}
+
+/// Evenly spaced values from `var.min_value` to `var.max_value`, `steps + 1` in all.
+fn sensitivity_steps(var: &SensitivityVariable, steps: usize) -> Vec<f64> {
+    let step_size = (var.max_value - var.min_value) / steps.max(1) as f64;
+    (0..=steps).map(|i| var.min_value + step_size * i as f64).collect()
+}
+
+/// Vary two variables together over the cartesian product of their ranges
+/// (`steps + 1` values each), so interaction effects show up in the grid.
+pub fn run_two_way_sensitivity(
+    base_score: f64,
+    var_a: &SensitivityVariable,
+    var_b: &SensitivityVariable,
+    steps: usize,
+) -> TwoWaySensitivityResult {
+    let a_values = sensitivity_steps(var_a, steps);
+    let b_values = sensitivity_steps(var_b, steps);
+
+    let scores = a_values
+        .iter()
+        .map(|&a| {
+            b_values
+                .iter()
+                .map(|&b| (base_score + linear_impact(var_a, a) + linear_impact(var_b, b)).clamp(0.0, 100.0))
+                .collect()
+        })
+        .collect();
+
+    TwoWaySensitivityResult {
+        variable_a: var_a.name.clone(),
+        variable_b: var_b.name.clone(),
+        a_values,
+        b_values,
+        scores,
+    }
+}
+
+fn generate_sensitivity_recommendations(impacts: &[VariableImpact]) -> Vec<String> {
+    let mut recommendations: Vec<String> = Vec::new();
+
+    for impact in impacts {
+        if impact.is_critical {
+            if impact.correlation > 0.0 {
+                recommendations.push(format!(
+                    "Focus on maximizing '{}' - positive correlation with decision success",
+                    impact.variable_name
+                ));
+            } else {
+                recommendations.push(format!(
+                    "Minimize exposure to '{}' - negative correlation with decision success",
+                    impact.variable_name
+                ));
+            }
+        }
+
+        if impact.elasticity.abs() > 1.0 {
+            recommendations.push(format!(
+                "High sensitivity to '{}' (elasticity: {:.2}) - small changes have large effects",
+                impact.variable_name, impact.elasticity
+            ));
+        }
+    }
+
+    if recommendations.is_empty() {
+        recommendations.push("Decision appears robust to variable changes".to_string());
+    }
+
+    recommendations
+}
+
+// ============================================================================
+// DECISION DECAY ANALYSIS
+// ============================================================================
+
+/// Calculate decision decay and half-life
+pub fn calculate_decision_decay(config: DecisionDecayConfig) -> DecisionDecayResult {
+    let mut confidence_timeline: Vec<ConfidencePoint> = Vec::new();
+    let mut half_life_days: f64 = 0.0;
+    let mut half_life_found = false;
+
+    // Calculate aggregate decay rate
+    let total_decay_rate = mean_factor(&config, |f| f.decay_rate);
+    let total_volatility = mean_factor(&config, |f| f.volatility);
+
+    // Generate timeline
+    for day in 0..=config.time_horizon_days {
+        let current_confidence = confidence_on_day(&config, day as f64);
+
+        let volatility_margin = total_volatility * (day as f64).sqrt() / 10.0;
+        
+        confidence_timeline.push(ConfidencePoint {
+            day,
+            confidence: current_confidence,
+            upper_bound: (current_confidence + volatility_margin).min(100.0),
+            lower_bound: (current_confidence - volatility_margin).max(0.0),
+        });
+
+        // Find half-life
+        if !half_life_found && current_confidence <= config.initial_confidence / 2.0 {
+            half_life_days = day as f64;
+            half_life_found = true;
+        }
+    }
+
+    // If half-life not reached, extrapolate (shocks inside the horizon
+    // have already used up part of the halving)
+    if !half_life_found {
+        let target = 0.5 / shock_factor(&config, config.time_horizon_days as f64);
+        half_life_days = days_to_fraction(&config.decay_model, total_decay_rate, target);
+    }
+
+    // Classify decay
+    let decay_classification = if half_life_days > 180.0 {
+        DecayClassification::Stable
+    } else if half_life_days > 60.0 {
+        DecayClassification::Moderate
+    } else if half_life_days > 14.0 {
+        DecayClassification::Volatile
+    } else {
+        DecayClassification::Critical
+    };
+
+    // Stability score (0-100)
+    let stability_score = (half_life_days / 365.0 * 100.0).min(100.0);
+
+    // Critical review date: a calendar date when we know when the decision
+    // was made and it is representable, otherwise relative to now (a decay
+    // rate of 0 never halves, so the offset is infinite)
+    let review_offset = (half_life_days * 0.5).round();
+    let review_date = config
+        .start_date
+        .as_deref()
+        .and_then(parse_iso_date)
+        .filter(|_| review_offset.is_finite() && review_offset >= 0.0)
+        .and_then(|start| start.checked_add_days(chrono::Days::new(review_offset as u64)));
+    let critical_review_date = match review_date {
+        Some(date) => format_iso_date(date),
+        None => format!("{} days from now", review_offset),
+    };
+
+    // Recommendations
+    let recommendations = generate_decay_recommendations(&decay_classification, half_life_days);
+
+    DecisionDecayResult {
+        half_life_days,
+        confidence_timeline,
+        critical_review_date,
+        decay_classification,
+        stability_score,
+        recommendations,
+    }
+}
+
+/// Confidence on `day`: the curve `calculate_decision_decay` samples daily.
+fn confidence_on_day(config: &DecisionDecayConfig, day: f64) -> f64 {
+    let rate = mean_factor(config, |f| f.decay_rate);
+    config.initial_confidence * decay_fraction(&config.decay_model, rate, day) * shock_factor(config, day)
+}
+
+/// Average of `value` over the decay factors; no factors, no decay.
+fn mean_factor(config: &DecisionDecayConfig, value: impl Fn(&DecayFactor) -> f64) -> f64 {
+    if config.decay_factors.is_empty() {
+        return 0.0;
+    }
+    config.decay_factors.iter().map(value).sum::<f64>() / config.decay_factors.len() as f64
+}
+
+/// Combined multiplier from shocks that have happened by `day`.
+fn shock_factor(config: &DecisionDecayConfig, day: f64) -> f64 {
+    config
+        .shock_events
+        .iter()
+        .filter(|s| s.day as f64 <= day)
+        .map(|s| 1.0 - s.confidence_drop.clamp(0.0, 1.0))
+        .product()
+}
+
+/// Fraction of the initial confidence left after `day` days (before shocks).
+fn decay_fraction(model: &DecayModel, rate: f64, day: f64) -> f64 {
+    match *model {
+        DecayModel::Exponential => (-(rate * day / 100.0)).exp(),
+        DecayModel::Linear => (1.0 - rate * day / 100.0).max(0.0),
+        DecayModel::Sigmoid { midpoint_day, steepness } => {
+            // Logistic curve rescaled so day 0 is exactly 1.0
+            let mid = midpoint_day as f64;
+            (1.0 + (-steepness * mid).exp()) / (1.0 + (steepness * (day - mid)).exp())
+        }
+    }
+}
+
+/// Inverse of `decay_fraction`: days until `fraction` (0..1) remains.
+fn days_to_fraction(model: &DecayModel, rate: f64, fraction: f64) -> f64 {
+    match *model {
+        DecayModel::Exponential => (fraction.ln() / (rate / 100.0)).abs(),
+        DecayModel::Linear => ((1.0 - fraction) / (rate / 100.0)).abs(),
+        DecayModel::Sigmoid { midpoint_day, steepness } => {
+            let mid = midpoint_day as f64;
+            let x = (1.0 + (-steepness * mid).exp()) / fraction - 1.0;
+            (mid + x.ln() / steepness).max(0.0)
+        }
+    }
+}
+
+/// SVG path `d` for the confidence line, scaled to a `width` x `height` box
+/// (confidence 100 at the top). Empty timeline gives an empty string.
+pub fn decay_sparkline_path(result: &DecisionDecayResult, width: f64, height: f64) -> String {
+    let points: Vec<(f64, f64)> = sparkline_points(result, width, height, |p| p.confidence);
+    svg_path(&points)
+}
+
+/// SVG path `d` for the confidence band: upper bound left-to-right, lower
+/// bound back, closed. Pair with `decay_sparkline_path` as a second path.
+pub fn decay_band_path(result: &DecisionDecayResult, width: f64, height: f64) -> String {
+    let mut points = sparkline_points(result, width, height, |p| p.upper_bound);
+    let lower = sparkline_points(result, width, height, |p| p.lower_bound);
+    if points.is_empty() {
+        return String::new();
+    }
+    points.extend(lower.into_iter().rev());
+    format!("{} Z", svg_path(&points))
+}
+
+fn sparkline_points(
+    result: &DecisionDecayResult,
+    width: f64,
+    height: f64,
+    value: impl Fn(&ConfidencePoint) -> f64,
+) -> Vec<(f64, f64)> {
+    let n = result.confidence_timeline.len();
+    let step = if n > 1 { width / (n - 1) as f64 } else { 0.0 };
+    result
+        .confidence_timeline
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let y = height - (value(p).clamp(0.0, 100.0) / 100.0) * height;
+            (i as f64 * step, y)
+        })
+        .collect()
+}
+
+fn svg_path(points: &[(f64, f64)]) -> String {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, (x, y))| format!("{}{:.2},{:.2}", if i == 0 { "M" } else { "L" }, x, y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `score` discounted by how quickly the decision decays, using the
+/// default `DurabilityDiscounts`.
+pub fn durability_adjusted_score(score: u32, decay: &DecisionDecayResult) -> f64 {
+    durability_adjusted_score_with(score, decay, &DurabilityDiscounts::default())
+}
+
+/// `durability_adjusted_score` with caller-chosen discount factors.
+pub fn durability_adjusted_score_with(
+    score: u32,
+    decay: &DecisionDecayResult,
+    discounts: &DurabilityDiscounts,
+) -> f64 {
+    let factor = match decay.decay_classification {
+        DecayClassification::Stable => discounts.stable,
+        DecayClassification::Moderate => discounts.moderate,
+        DecayClassification::Volatile => discounts.volatile,
+        DecayClassification::Critical => discounts.critical,
+    };
+    score as f64 * factor.clamp(0.0, 1.0)
+}
+
+/// How far a decision is past its critical review point as of `now_iso`.
+/// Requires `config.start_date`; both dates are read as `YYYY-MM-DD` prefixes.
+pub fn decision_debt(config: &DecisionDecayConfig, now_iso: &str) -> Result<DecisionDebt, DecisionDebtError> {
+    let start_iso = config.start_date.as_deref().ok_or(DecisionDebtError::MissingStartDate)?;
+    let parse = |iso: &str| parse_iso_date(iso).ok_or_else(|| DecisionDebtError::InvalidDate(iso.to_string()));
+    let start = parse(start_iso)?;
+    let now = parse(now_iso)?;
+
+    let decay = calculate_decision_decay(config.clone());
+    let half_life = decay.half_life_days;
+    let critical_review_day = half_life * 0.5;
+
+    let days_since_decision = (now - start).num_days();
+    let elapsed = days_since_decision as f64;
+    let days_overdue = (elapsed - critical_review_day).max(0.0);
+
+    // Same decay model and shocks as `decay`'s timeline
+    let confidence_erosion = if days_overdue > 0.0 {
+        confidence_on_day(config, critical_review_day) - confidence_on_day(config, elapsed)
+    } else {
+        0.0
+    };
+
+    let level = if days_overdue <= 0.0 {
+        DebtLevel::None
+    } else if elapsed >= half_life {
+        DebtLevel::High
+    } else {
+        DebtLevel::Low
+    };
+
+    Ok(DecisionDebt {
+        days_since_decision,
+        critical_review_day,
+        days_overdue,
+        confidence_erosion,
+        level,
+    })
+}
+
+/// Compare the report's stated HALF-LIFE with the one implied by `decay`.
+/// `None` when nothing is stated or the two agree within `half_life_tolerance`.
+pub fn validate_half_life_claim(input: &str, decay: &DecisionDecayConfig) -> Option<HalfLifeMismatch> {
+    let cleaned = clean_model_text(input);
+    let stated_days = default_section_text(&cleaned, "HALF-LIFE").and_then(parse_duration_days)?;
+    let computed_days = calculate_decision_decay(decay.clone()).half_life_days;
+    if stated_days <= 0.0 || computed_days <= 0.0 {
+        return None;
+    }
+
+    let ratio = stated_days.max(computed_days) / stated_days.min(computed_days);
+    if ratio <= decay.half_life_tolerance {
+        return None;
+    }
+    Some(HalfLifeMismatch {
+        stated_days,
+        computed_days,
+        ratio,
+    })
+}
+
+/// First duration in `text` ("180 days", "6 months", "two weeks") in days.
+fn parse_duration_days(text: &str) -> Option<f64> {
+    let re = &*DURATION_RE;
+    let words = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven", "twelve",
+    ];
+
+    let c = re.captures(text)?;
+    let amount = c[1].parse::<f64>().ok().or_else(|| {
+        let w = c[1].to_lowercase();
+        words.iter().position(|n| *n == w).map(|i| (i + 1) as f64)
+    })?;
+    let unit_days = match c[2].to_lowercase().as_str() {
+        "day" => 1.0,
+        "week" => 7.0,
+        "month" => 30.0,
+        "quarter" => 91.0,
+        _ => 365.0,
+    };
+    Some(amount * unit_days)
+}
+
+/// Calendar date of an ISO `YYYY-MM-DD` prefix; any time part is ignored.
+fn parse_iso_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Inverse of `parse_iso_date`.
+fn format_iso_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+fn generate_decay_recommendations(classification: &DecayClassification, half_life: f64) -> Vec<String> {
+    let mut recs = Vec::new();
+
+    match classification {
+        DecayClassification::Critical => {
+            recs.push("URGENT: Decision has very short validity window".to_string());
+            recs.push(format!("Schedule review within {} days", (half_life * 0.3).round() as u32));
+            recs.push("Consider if decision can be made more stable".to_string());
+        }
+        DecayClassification::Volatile => {
+            recs.push("Decision requires frequent monitoring".to_string());
+            recs.push(format!("Plan for review every {} days", (half_life * 0.4).round() as u32));
+            recs.push("Identify key assumptions that drive volatility".to_string());
+        }
+        DecayClassification::Moderate => {
+            recs.push("Decision has reasonable stability".to_string());
+            recs.push(format!("Schedule quarterly review (every {} days)", (half_life * 0.5).round() as u32));
+        }
+        DecayClassification::Stable => {
+            recs.push("Decision is highly stable".to_string());
+            recs.push("Annual review recommended".to_string());
+            recs.push("Monitor for black swan events that could invalidate assumptions".to_string());
+        }
+    }
+
+    recs
+}
+
+// ============================================================================
+// CALIBRATION / EVALUATION
+// ============================================================================
+
+/// Bucket a 0-100 score into a letter grade (same bands as the app's readiness grade).
+pub fn grade_for_score(score: u32) -> Grade {
+    match score {
+        85.. => Grade::A,
+        70..=84 => Grade::B,
+        55..=69 => Grade::C,
+        _ => Grade::D,
+    }
+}
+
+/// Fraction of `results` in each grade band; grades with no reports are
+/// left out and an empty slice gives an empty map. Bands are fixed today,
+/// `_cfg` is reserved for per-rubric bands.
+pub fn grade_distribution(results: &[ScoreResult], _cfg: &ScoringConfig) -> HashMap<Grade, f64> {
+    let mut distribution: HashMap<Grade, f64> = HashMap::new();
+    if results.is_empty() {
+        return distribution;
+    }
+
+    let share = 1.0 / results.len() as f64;
+    for r in results {
+        *distribution.entry(grade_for_score(r.score)).or_insert(0.0) += share;
+    }
+    distribution
+}
+
+/// Measure how well model scores agree with human ratings of the same reports.
+/// Returns an error if the slices differ in length or are empty.
+pub fn agreement(model: &[u32], human: &[u32]) -> Result<Agreement, String> {
+    if model.len() != human.len() {
+        return Err(format!(
+            "Length mismatch: {} model scores vs {} human scores",
+            model.len(),
+            human.len()
+        ));
+    }
+    if model.is_empty() {
+        return Err("No scores to compare".to_string());
+    }
+
+    let n = model.len() as f64;
+    let xs: Vec<f64> = model.iter().map(|&x| x as f64).collect();
+    let ys: Vec<f64> = human.iter().map(|&y| y as f64).collect();
+
+    let mean_absolute_error = xs.iter().zip(&ys).map(|(x, y)| (x - y).abs()).sum::<f64>() / n;
+
+    // Pearson correlation (0 when either side has no variance)
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let mean_y = ys.iter().sum::<f64>() / n;
+    let cov: f64 = xs.iter().zip(&ys).map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let var_x: f64 = xs.iter().map(|x| (x - mean_x).powi(2)).sum();
+    let var_y: f64 = ys.iter().map(|y| (y - mean_y).powi(2)).sum();
+    let pearson_correlation = if var_x > 0.0 && var_y > 0.0 {
+        cov / (var_x.sqrt() * var_y.sqrt())
+    } else {
+        0.0
+    };
+
+    // Cohen's kappa over letter grades
+    let grades = [Grade::A, Grade::B, Grade::C, Grade::D];
+    let model_grades: Vec<Grade> = model.iter().map(|&s| grade_for_score(s)).collect();
+    let human_grades: Vec<Grade> = human.iter().map(|&s| grade_for_score(s)).collect();
+
+    let observed = model_grades
+        .iter()
+        .zip(&human_grades)
+        .filter(|(a, b)| a == b)
+        .count() as f64
+        / n;
+    let expected: f64 = grades
+        .iter()
+        .map(|g| {
+            let pm = model_grades.iter().filter(|x| *x == g).count() as f64 / n;
+            let ph = human_grades.iter().filter(|x| *x == g).count() as f64 / n;
+            pm * ph
+        })
+        .sum();
+    let cohens_kappa = if expected < 1.0 {
+        (observed - expected) / (1.0 - expected)
+    } else {
+        1.0
+    };
+
+    Ok(Agreement {
+        sample_count: model.len(),
+        mean_absolute_error,
+        pearson_correlation,
+        cohens_kappa,
+    })
+}
+
+// ============================================================================
+// PORTFOLIO AGGREGATION
+// ============================================================================
+
+/// Blend many scored decisions, each weighted (e.g. by budget). Negative
+/// weights are treated as zero.
+pub fn portfolio_score(items: &[(f64, ScoreResult)]) -> PortfolioScore {
+    let total_weight: f64 = items.iter().map(|(w, _)| w.max(0.0)).sum();
+
+    let (weighted_score, must_repair_share) = if total_weight > 0.0 {
+        let score_sum: f64 = items.iter().map(|(w, r)| w.max(0.0) * r.score as f64).sum();
+        let repair_sum: f64 = items
+            .iter()
+            .filter(|(_, r)| r.must_repair)
+            .map(|(w, _)| w.max(0.0))
+            .sum();
+        (score_sum / total_weight, repair_sum / total_weight)
+    } else {
+        (0.0, 0.0)
+    };
+
+    let worst = items.iter().enumerate().min_by_key(|(_, (_, r))| r.score);
+
+    PortfolioScore {
+        total_weight,
+        weighted_score,
+        worst_index: worst.map(|(i, _)| i),
+        worst_score: worst.map(|(_, (_, r))| r.score),
+        must_repair_share,
+    }
+}
+
+// ============================================================================
+// EXPORT
+// ============================================================================
+
+/// One JSON artifact holding every analysis that was run, see `AnalysisBundle`.
+pub fn bundle(
+    score: &ScoreResult,
+    mc: Option<&MonteCarloResult>,
+    sens: Option<&SensitivityResult>,
+    decay: Option<&DecisionDecayResult>,
+) -> String {
+    let bundle = AnalysisBundle {
+        schema_version: BUNDLE_SCHEMA_VERSION,
+        generated_at: current_timestamp(),
+        score: score.clone(),
+        monte_carlo: mc.cloned(),
+        sensitivity: sens.cloned(),
+        decay: decay.cloned(),
+    };
+
+    serde_json::to_string(&bundle).unwrap_or_default()
+}
+
+/// JSON Schema (draft 2020-12) document whose `$defs` cover `ScoreResult`,
+/// `MonteCarloResult` and everything they reference, for generating client
+/// types.
+#[cfg(feature = "schema")]
+pub fn export_schemas() -> String {
+    export_schemas_with(|_| {})
+}
+
+/// `export_schemas` plus whatever `extra` registers on the generator, so
+/// callers can bundle their own input types into the same document.
+#[cfg(feature = "schema")]
+pub fn export_schemas_with(extra: impl FnOnce(&mut schemars::SchemaGenerator)) -> String {
+    let mut generator = schemars::SchemaGenerator::default();
+    generator.subschema_for::<ScoreResult>();
+    generator.subschema_for::<MonteCarloResult>();
+    extra(&mut generator);
+
+    let doc = serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "score_engine",
+        "$defs": generator.take_definitions(true),
+    });
+    serde_json::to_string_pretty(&doc).unwrap()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn current_timestamp() -> Option<String> {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?
+        .as_secs() as i64;
+    let now = chrono::DateTime::from_timestamp(secs, 0)?;
+    Some(now.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+}
+
+// std's clock panics on wasm32-unknown-unknown
+#[cfg(target_arch = "wasm32")]
+fn current_timestamp() -> Option<String> {
+    None
+}
+
+// ============================================================================
+// SECTION ANALYSIS
+// ============================================================================
+
+const DEFAULT_HEADERS: [&str; 7] = [
+    "BEST OPTION",
+    "RATIONALE",
+    "TOP RISKS",
+    "ASSUMPTIONS TO VALIDATE",
+    "HALF-LIFE",
+    "BLIND SPOTS",
+    "NEXT ACTIONS",
+];
+
+/// Marker `minimal_template` puts in front of each prompt.
+const TEMPLATE_PROMPT: &str = "FILL IN:";
+
+/// Unfilled placeholder tokens in a section, in order: TODO, TBD, FIXME,
+/// XXX, `[...]` and `<...>`. Checkboxes (`[ ]`, `[x]`), numeric footnotes,
+/// Markdown links and `minimal_template`'s own `[FILL IN: ...]` prompts are
+/// not placeholders.
+pub fn detect_placeholders(section: &str) -> Vec<String> {
+    let placeholder_re = &*PLACEHOLDER_RE;
+
+    placeholder_re
+        .captures_iter(section)
+        .filter(|c| match c.get(1) {
+            Some(inner) => {
+                let inner = inner.as_str().trim();
+                c.get(2).is_none()
+                    && !inner.is_empty()
+                    && !inner.starts_with(TEMPLATE_PROMPT)
+                    && !inner.eq_ignore_ascii_case("x")
+                    && !inner.chars().all(|ch| ch.is_ascii_digit())
+            }
+            None => true,
+        })
+        .map(|c| c[0].to_string())
+        .collect()
+}
+
+/// Original-case body of `header`'s section in cleaned text, up to the next
+/// known header. Errors when a pattern built from `headers` won't compile.
+fn section_text<'a, S: AsRef<str>>(
+    cleaned: &'a str,
+    header: &str,
+    headers: &[S],
+) -> Result<Option<&'a str>, ScoreError> {
+    let header_re = cached_pattern(&format!(r"(?mi)^[ \t]*{}[ \t]*:?[ \t]*$", regex::escape(header)))?;
+    let Some(m) = header_re.find(cleaned) else {
+        return Ok(None);
+    };
+    let after = &cleaned[m.end()..];
+
+    let next_re = cached_pattern(&format!(
+        r"(?mi)^[ \t]*({})[ \t]*:?[ \t]*$",
+        headers.iter().map(|h| regex::escape(h.as_ref())).collect::<Vec<_>>().join("|")
+    ))?;
+    let end_idx = next_re.find(after).map(|x| x.start()).unwrap_or(after.len());
+
+    Ok(Some(after[..end_idx].trim_matches('\n')))
+}
+
+/// `section_text` among `DEFAULT_HEADERS`, whose patterns always compile.
+fn default_section_text<'a>(cleaned: &'a str, header: &str) -> Option<&'a str> {
+    section_text(cleaned, header, &DEFAULT_HEADERS).expect("default header patterns compile")
+}
+
+/// Group a section into list items; indented or unmarked lines after an
+/// item are treated as part of it (sub-bullets, wrapped text).
+fn section_items(section: &str) -> Vec<String> {
+    let item_re = &*LIST_ITEM_RE;
+    let mut items: Vec<String> = Vec::new();
+
+    for line in section.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let top_level = !line.starts_with(' ') && !line.starts_with('\t');
+        match item_re.captures(line.trim()) {
+            Some(c) if top_level || items.is_empty() => items.push(c[1].to_string()),
+            _ => {
+                if let Some(last) = items.last_mut() {
+                    last.push('\n');
+                    last.push_str(line.trim());
+                }
+            }
+        }
+    }
+
+    items
+}
+
+/// Distribution of NEXT ACTIONS across owners, flagging a single owner who
+/// holds more than `cfg.max_owner_share` of all actions.
+pub fn owner_concentration(input: &str, cfg: &ScoringConfig) -> OwnerConcentration {
+    let owner_re = &*OWNER_NAME_RE;
+
+    let cleaned = clean_model_text(input);
+    let section = default_section_text(&cleaned, "NEXT ACTIONS").unwrap_or_default();
+    let table = table_actions(section);
+
+    // Table rows carry the owner in a column; list items name it inline
+    let owned: Vec<Option<String>> = if table.is_empty() {
+        section_items(section)
+            .iter()
+            .map(|item| owner_re.captures(item).map(|c| c[1].to_string()))
+            .collect()
+    } else {
+        table.into_iter().map(|row| row.owner).collect()
+    };
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut unowned_actions = 0;
+    for owner in &owned {
+        match owner {
+            Some(name) => *counts.entry(name.clone()).or_insert(0) += 1,
+            None => unowned_actions += 1,
+        }
+    }
+
+    let mut owners: Vec<(String, usize)> = counts.into_iter().collect();
+    owners.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let total_actions = owned.len();
+    let top_owner = owners.first().map(|(name, _)| name.clone());
+    let top_share = match owners.first() {
+        Some((_, n)) if total_actions > 0 => *n as f64 / total_actions as f64,
+        _ => 0.0,
+    };
+
+    OwnerConcentration {
+        total_actions,
+        owners,
+        unowned_actions,
+        top_owner,
+        top_share,
+        concentrated: top_share > cfg.max_owner_share,
+    }
+}
+
+/// Rows of a Markdown table in NEXT ACTIONS, with owner and due columns
+/// picked out by header name. Empty when the section is not a table.
+pub fn next_actions_table(input: &str) -> Vec<TableAction> {
+    let cleaned = clean_model_text(input);
+    default_section_text(&cleaned, "NEXT ACTIONS")
+        .map(table_actions)
+        .unwrap_or_default()
+}
+
+const OWNER_COLUMNS: [&str; 5] = ["owner", "assignee", "assigned to", "responsible", "who"];
+const DUE_COLUMNS: [&str; 5] = ["due", "deadline", "due date", "when", "by"];
+
+fn table_actions(section: &str) -> Vec<TableAction> {
+    let Some((header, rows)) = parse_table(section) else {
+        return Vec::new();
+    };
+    let column = |names: &[&str]| header.iter().position(|h| names.contains(&h.to_lowercase().as_str()));
+    let owner_col = column(&OWNER_COLUMNS);
+    let due_col = column(&DUE_COLUMNS);
+    // The action is the first column that isn't owner or due
+    let action_col = (0..header.len())
+        .find(|i| Some(*i) != owner_col && Some(*i) != due_col)
+        .unwrap_or(0);
+
+    let cell = |row: &[String], col: Option<usize>| {
+        col.and_then(|i| row.get(i)).filter(|c| !c.is_empty()).cloned()
+    };
+    rows.iter()
+        .map(|row| TableAction {
+            action: cell(row, Some(action_col)).unwrap_or_default(),
+            owner: cell(row, owner_col),
+            due: cell(row, due_col),
+        })
+        .collect()
+}
+
+/// First pipe-delimited table in `section`: header cells and data rows.
+/// Requires a `---|---` separator under the header; it is not a row itself.
+fn parse_table(section: &str) -> Option<(Vec<String>, Vec<Vec<String>>)> {
+    let separator_re = &*TABLE_SEPARATOR_RE;
+    let cells = |line: &str| -> Vec<String> {
+        line.trim()
+            .trim_start_matches('|')
+            .trim_end_matches('|')
+            .split('|')
+            .map(|c| c.trim().to_string())
+            .collect()
+    };
+
+    let lines: Vec<&str> = section.lines().map(str::trim).collect();
+    let sep = lines
+        .iter()
+        .position(|l| separator_re.is_match(l))
+        .filter(|&i| i > 0 && lines[i - 1].contains('|'))?;
+
+    let rows = lines[sep + 1..]
+        .iter()
+        .take_while(|l| l.contains('|'))
+        .map(|l| cells(l))
+        .filter(|row| row.iter().any(|c| !c.is_empty()))
+        .collect();
+    Some((cells(lines[sep - 1]), rows))
+}
+
+/// Quality metrics for each required section on its own. Missing sections
+/// are reported with `present: false` and zeroed metrics.
+pub fn section_metrics(input: &str, cfg: &ScoringConfig) -> Vec<SectionMetrics> {
+    let cleaned = clean_model_text(input);
+    let lexicon = quality_lexicon(cfg, &cleaned);
+
+    cfg.required_headers
+        .iter()
+        .map(|h| match section_text(&cleaned, h, &cfg.required_headers).unwrap_or_else(|e| panic!("{}", e)) {
+            Some(body) => SectionMetrics {
+                header: h.clone(),
+                present: true,
+                metrics: calculate_quality_metrics(body, &lexicon, cfg.run_on_sentence_words, &cfg.quality_weights),
+            },
+            None => SectionMetrics {
+                header: h.clone(),
+                present: false,
+                metrics: QualityMetrics::default(),
+            },
+        })
+        .collect()
+}
+
+/// Score `input` relative to a gold-standard exemplar, section by section.
+pub fn compare_to_exemplar(input: &str, exemplar: &str, cfg: &ScoringConfig) -> ComparisonReport {
+    let ours = score_report_text(input, cfg.clone());
+    let theirs = score_report_text(exemplar, cfg.clone());
+
+    let section_deltas: Vec<SectionDelta> = section_metrics(input, cfg)
+        .into_iter()
+        .zip(section_metrics(exemplar, cfg))
+        .map(|(a, b)| SectionDelta {
+            header: a.header,
+            input_quality: a.metrics.overall_quality,
+            exemplar_quality: b.metrics.overall_quality,
+            delta: a.metrics.overall_quality - b.metrics.overall_quality,
+        })
+        .collect();
+
+    let below_exemplar = section_deltas
+        .iter()
+        .filter(|d| d.delta < -0.05)
+        .map(|d| d.header.clone())
+        .collect();
+
+    ComparisonReport {
+        score_delta: ours.score as i32 - theirs.score as i32,
+        composite_delta: ours.composite_score - theirs.composite_score,
+        section_deltas,
+        below_exemplar,
+    }
+}
+
+/// Score delta, header changes and per-metric quality deltas from `before`
+/// to `after` (e.g. a model's first and second draft).
+pub fn compare_reports(before: &str, after: &str, cfg: ScoringConfig) -> ReportDiff {
+    let old = score_report_text(before, cfg.clone());
+    let new = score_report_text(after, cfg);
+
+    let headers_added = old.missing_headers
+        .iter()
+        .filter(|h| !new.missing_headers.contains(h))
+        .cloned()
+        .collect();
+    let headers_removed = new.missing_headers
+        .iter()
+        .filter(|h| !old.missing_headers.contains(h))
+        .cloned()
+        .collect();
+
+    let metrics = |m: &QualityMetrics| {
+        [
+            ("clarity_score", m.clarity_score),
+            ("specificity_score", m.specificity_score),
+            ("actionability_score", m.actionability_score),
+            ("completeness_score", m.completeness_score),
+            ("overall_quality", m.overall_quality),
+            ("run_on_sentences", m.run_on_sentences as f64),
+            ("jargon_score", m.jargon_score),
+            ("decisiveness_score", m.decisiveness_score),
+            ("lexical_diversity", m.lexical_diversity),
+            ("passive_voice_ratio", m.passive_voice_ratio),
+        ]
+    };
+    let quality_deltas = metrics(&old.quality_metrics)
+        .into_iter()
+        .zip(metrics(&new.quality_metrics))
+        .map(|((metric, before), (_, after))| MetricDelta {
+            metric: metric.to_string(),
+            before,
+            after,
+            delta: after - before,
+        })
+        .collect();
+
+    ReportDiff {
+        score_before: old.score,
+        score_after: new.score,
+        score_delta: new.score as i32 - old.score as i32,
+        headers_added,
+        headers_removed,
+        quality_deltas,
+    }
+}
+
+// Claims and the phrases that contradict them when they show up in another
+// section, e.g. "low risk" in RATIONALE against "severe" in TOP RISKS
+const CONTRADICTION_PAIRS: [(&[&str], &[&str]); 4] = [
+    (
+        &["low risk", "minimal risk", "no risk", "risk-free", "safe bet"],
+        &["high risk", "high severity", "severe", "critical", "catastrophic"],
+    ),
+    (
+        &["cheap", "inexpensive", "low cost", "low-cost"],
+        &["expensive", "costly", "high cost", "over budget"],
+    ),
+    (&["simple", "easy", "straightforward"], &["complex", "complicated", "difficult"]),
+    (&["proven", "well-tested", "battle-tested"], &["unproven", "untested", "experimental"]),
+];
+
+/// Antonym clashes between sections, one finding per pair: a claim in one
+/// section (by header name order) and its opposite in another.
+pub fn detect_contradictions(sections: &HashMap<String, String>) -> Vec<String> {
+    let mut headers: Vec<&String> = sections.keys().collect();
+    headers.sort();
+    let lower: HashMap<&String, String> = headers.iter().map(|h| (*h, sections[*h].to_lowercase())).collect();
+    let find = |phrases: &PhrasePatterns, header: &String| -> Option<String> {
+        phrases
+            .iter()
+            .find(|(_, re)| re.is_match(&lower[header]))
+            .map(|(p, _)| p.to_string())
+    };
+
+    let mut findings = Vec::new();
+    for (claims, counters) in CONTRADICTION_PATTERNS.iter() {
+        let clash = headers.iter().find_map(|a| {
+            let claim = find(claims, a)?;
+            headers
+                .iter()
+                .filter(|b| *b != a)
+                .find_map(|b| find(counters, b).map(|counter| (a, claim.clone(), b, counter)))
+        });
+        if let Some((a, claim, b, counter)) = clash {
+            findings.push(format!("{} says \"{}\" but {} says \"{}\"", a, claim, b, counter));
+        }
+    }
+    findings
+}
+
+// Likelihood and severity vocabulary for risk lines
+const LIKELIHOOD_WORDS: [&str; 14] = [
+    "likely", "unlikely", "probable", "probably", "possible", "rare", "rarely", "frequent",
+    "occasional", "almost certain", "chance", "odds", "probability", "likelihood",
+];
+const IMPACT_WORDS: [&str; 12] = [
+    "high impact", "low impact", "medium impact", "severe", "severity", "critical", "major",
+    "minor", "moderate", "catastrophic", "negligible", "impact:",
+];
+
+/// TOP RISKS lines with neither a likelihood nor an impact indicator
+/// (a percentage or money amount counts as one).
+pub fn unquantified_risks(input: &str) -> Vec<String> {
+    let quantity_re = &*QUANTITY_RE;
+
+    let cleaned = clean_model_text(input);
+    let items = default_section_text(&cleaned, "TOP RISKS")
+        .map(section_items)
+        .unwrap_or_default();
+
+    items
+        .iter()
+        .filter(|item| {
+            let lower = item.to_lowercase();
+            !quantity_re.is_match(&lower)
+                && !LIKELIHOOD_WORDS.iter().any(|w| lower.contains(w))
+                && !IMPACT_WORDS.iter().any(|w| lower.contains(w))
+        })
+        .map(|item| item.lines().next().unwrap_or_default().to_string())
+        .collect()
+}
+
+// Vocabulary of independent reasons: cost, risk, evidence
+const REASON_WORDS: [&str; 24] = [
+    "cost", "cheap", "budget", "price", "revenue", "margin", "roi", "save", "saving", "risk",
+    "evidence", "data", "benchmark", "measured", "survey", "customer", "users", "pilot",
+    "faster", "latency", "compliance", "because", "since", "%",
+];
+// Filler that carries no reason of its own
+const CIRCULAR_FILLER: [&str; 16] = [
+    "the", "a", "an", "is", "are", "we", "should", "it", "this", "that", "best", "better",
+    "option", "choice", "right", "clearly",
+];
+
+/// True when RATIONALE only restates BEST OPTION: no reason vocabulary and
+/// at least half of its remaining words come from the option itself.
+pub fn detect_circular_rationale(input: &str) -> bool {
+    let cleaned = clean_model_text(input);
+    let (Some(option), Some(rationale)) = (
+        default_section_text(&cleaned, "BEST OPTION"),
+        default_section_text(&cleaned, "RATIONALE"),
+    ) else {
+        return false;
+    };
+
+    let words = |text: &str| -> Vec<String> {
+        text.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty() && !CIRCULAR_FILLER.contains(w))
+            .map(str::to_string)
+            .collect()
+    };
+    let option_words = words(option);
+    let rationale_words = words(rationale);
+    if rationale_words.is_empty() {
+        return false;
+    }
+
+    let lower = rationale.to_lowercase();
+    if REASON_WORDS.iter().any(|w| lower.contains(w)) {
+        return false;
+    }
+
+    let restated = rationale_words.iter().filter(|w| option_words.contains(w)).count();
+    restated * 2 >= rationale_words.len()
+}
+
+// Cues that a figure is an estimate, not a promise
+const UNCERTAINTY_CUES: [&str; 14] = [
+    "~", "±", "+/-", "approximately", "approx", "about", "around", "roughly", "estimated",
+    "estimate", "between", "up to", "at least", "range",
+];
+
+/// Sentences stating a precise money or percentage figure ("revenue rises by
+/// exactly $2.3M") with no range or uncertainty cue in the same sentence.
+/// Ranges ("$2-3M", "10 to 15%") and hedged figures ("~$2M") are exempt.
+pub fn flag_point_estimates(input: &str) -> Vec<String> {
+    let figure_re = &*FIGURE_RE;
+    let range_re = &*RANGE_RE;
+    let sentence_end_re = &*SENTENCE_END_RE;
+    let header_re = &*HEADER_LINE_RE;
+
+    let cleaned = clean_model_text(input);
+    cleaned
+        .lines()
+        .filter(|l| !header_re.is_match(l.trim()))
+        .flat_map(|l| sentence_end_re.split(l).map(str::to_string).collect::<Vec<_>>())
+        .map(|s| s.trim().trim_start_matches(['-', '*', '•']).trim().to_string())
+        .filter(|s| {
+            let lower = s.to_lowercase();
+            figure_re.is_match(s)
+                && !range_re.is_match(s)
+                && !UNCERTAINTY_CUES.iter().any(|c| lower.contains(c))
+        })
+        .collect()
+}
+
+/// ASSUMPTIONS TO VALIDATE items with their status, read from a checkbox
+/// (`[x]`, `[ ]`) or a parenthesized cue such as `(validated)`. Items
+/// without a cue are `Pending`.
+pub fn parse_assumptions(input: &str) -> Vec<Assumption> {
+    let cue_re = &*ASSUMPTION_CUE_RE;
+
+    let cleaned = clean_model_text(input);
+    let items = default_section_text(&cleaned, "ASSUMPTIONS TO VALIDATE")
+        .map(section_items)
+        .unwrap_or_default();
+
+    items
+        .iter()
+        .map(|item| {
+            let line = item.lines().next().unwrap_or_default();
+            let mut status = ValidationStatus::Pending;
+            for c in cue_re.captures_iter(line) {
+                let cue = c.get(1).or_else(|| c.get(2)).unwrap().as_str().to_lowercase();
+                status = match cue.as_str() {
+                    "x" | "validated" | "confirmed" => ValidationStatus::Validated,
+                    "invalidated" | "disproven" => ValidationStatus::Invalidated,
+                    _ => ValidationStatus::Pending,
+                };
+            }
+            Assumption {
+                text: cue_re.replace_all(line, "").trim().to_string(),
+                status,
+            }
+        })
+        .collect()
+}
+
+/// How many TOP RISKS items carry a mitigation cue on the same or a nested line.
+pub fn risk_mitigation_coverage(input: &str) -> MitigationCoverage {
+    let cues = [
+        "mitigat", "fallback", "contingency", "to address this", "workaround", "plan b", "hedge",
+    ];
+
+    let cleaned = clean_model_text(input);
+    let items = default_section_text(&cleaned, "TOP RISKS")
+        .map(section_items)
+        .unwrap_or_default();
+
+    let mut unpaired_risks: Vec<String> = Vec::new();
+    for item in &items {
+        let lower = item.to_lowercase();
+        if !cues.iter().any(|c| lower.contains(c)) {
+            unpaired_risks.push(item.lines().next().unwrap_or_default().to_string());
+        }
+    }
+
+    let total_risks = items.len();
+    let mitigated_risks = total_risks - unpaired_risks.len();
+    MitigationCoverage {
+        total_risks,
+        mitigated_risks,
+        coverage: if total_risks > 0 {
+            mitigated_risks as f64 / total_risks as f64
+        } else {
+            1.0
+        },
+        unpaired_risks,
+    }
+}
+
+// ============================================================================
+// PII REDACTION
+// ============================================================================
+
+/// Replace email addresses, phone numbers and name-like patterns with placeholders.
+/// Returns the redacted text and the redactions (spans refer to the original text).
+pub fn redact_pii(text: &str) -> (String, Vec<Redaction>) {
+    let mut found: Vec<Redaction> = Vec::new();
+    for (kind, re, placeholder) in PII_PATTERNS.iter() {
+        for m in re.find_iter(text) {
+            let (mut start, end) = (m.start(), m.end());
+            // Keep the label of "Owner: Jane Doe", redact only the name.
+            if let Some(colon) = m.as_str().find(':') {
+                start += colon + 1;
+                start += text[start..end].len() - text[start..end].trim_start().len();
+            }
+            found.push(Redaction {
+                kind: *kind,
+                start,
+                end,
+                placeholder: placeholder.to_string(),
+            });
+        }
+    }
+
+    // Earliest first; drop anything overlapping an earlier redaction.
+    found.sort_by_key(|r| (r.start, std::cmp::Reverse(r.end)));
+    let mut redactions: Vec<Redaction> = Vec::new();
+    for r in found {
+        if redactions.last().is_none_or(|prev| r.start >= prev.end) {
+            redactions.push(r);
+        }
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for r in &redactions {
+        out.push_str(&text[cursor..r.start]);
+        out.push_str(&r.placeholder);
+        cursor = r.end;
+    }
+    out.push_str(&text[cursor..]);
+
+    (out, redactions)
+}
+
+// ============================================================================
+// TEXT PROCESSING HELPERS
+// ============================================================================
+
+// Fixed patterns, including those built from the constant word lists, are
+// compiled once per process (see `benches/scoring.rs`). Patterns built from
+// the configured headers or a lexicon go through `cached_pattern`.
+
+/// Patterns that depend on the config, compiled on first use. One entry per
+/// distinct header list or lexicon pattern, so it stays small in practice.
+static CONFIG_PATTERNS: LazyLock<Mutex<HashMap<String, Result<Regex, regex::Error>>>> =
+    LazyLock::new(Default::default);
+
+fn cached_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    let mut cache = CONFIG_PATTERNS.lock().unwrap_or_else(|e| e.into_inner());
+    cache
+        .entry(pattern.to_string())
+        .or_insert_with(|| Regex::new(pattern))
+        .clone()
+}
+
+/// Markdown `#` heading markers.
+static MD_HEADING_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s{0,3}#{1,6}\s+").unwrap());
+
+/// Horizontal rules (`---`, `===`, `___`).
+static RULE_LINE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*[-=_]{3,}\s*$").unwrap());
+
+/// Upper-case header lines ending in a colon.
+static COLON_HEADER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*([A-Z][A-Z0-9 \-]{2,})\s*:\s*$").unwrap());
+
+/// A whole trimmed line that reads as a header.
+static HEADER_LINE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Z0-9 \-]+:?$").unwrap());
+
+/// Header-like lines considered for fuzzy matching.
+static FUZZY_HEADER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[ \t]*([A-Za-z][A-Za-z0-9 \-/]{1,40}?)[ \t]*(:?)[ \t]*$").unwrap());
+
+/// Dash or star bullets.
+static BULLET_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*[-*]\s+\S+").unwrap());
+
+/// `1.` / `1)` numbered items.
+static NUMBERED_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*\d{1,2}[\.\)]\s+\S+").unwrap());
+
+/// Words in upper-cased text.
+static HEADER_WORD_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[A-Z0-9]{2,}").unwrap());
+
+/// The NEXT ACTIONS header in normalized text.
+static NEXT_ACTIONS_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*NEXT ACTIONS\s*:?\s*$").unwrap());
+
+/// A list item line; captures the item text.
+static LIST_ITEM_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?:[-*•]|\d{1,2}[\.\)])\s+(.*)$").unwrap());
+
+/// The `---|---` line under a table header.
+static TABLE_SEPARATOR_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\|?\s*:?-{3,}:?\s*(\|\s*:?-{3,}:?\s*)*\|?$").unwrap());
+
+/// Candidate placeholder tokens; see `detect_placeholders`.
+static PLACEHOLDER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\b(?:TODO|TBD|FIXME|XXX)\b|\[([^\]\n]*)\](\()?|<[A-Za-z][^<>\n]*>").unwrap());
+
+/// Acronyms, optionally pluralized.
+static ACRONYM_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b([A-Z]{2,5})s?\b").unwrap());
+
+/// An acronym followed by its expansion: "API (Application Programming Interface)".
+static ACRONYM_DEFINED_AFTER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b([A-Z]{2,5})\s*\([A-Za-z][^)]*\)").unwrap());
+
+/// An expansion followed by its acronym: "Application Programming Interface (API)".
+static ACRONYM_DEFINED_BEFORE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[A-Za-z][A-Za-z ]+\(([A-Z]{2,5})\)").unwrap());
+
+/// A percentage or money amount.
+static QUANTITY_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\d+(?:\.\d+)?\s*%|[$€£]\s*\d").unwrap());
+
+/// A precise money or percentage figure.
+static FIGURE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[$€£]\s?\d[\d,]*(?:\.\d+)?|\d+(?:\.\d+)?\s?%").unwrap());
+
+/// Numeric ranges ("$2-3M", "10 to 15%").
+static RANGE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\d\s*[kKmMbB%]?\s*(?:-|–|—|to)\s*[$€£]?\d").unwrap());
+
+/// Sentence-ending punctuation.
+static SENTENCE_END_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[.!?](?:\s+|$)").unwrap());
+
+/// A NEXT ACTIONS deadline cue: a standalone timeline word or an ISO date.
+static TIMELINE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"\b(?:{})\b|\d{{4}}-\d{{2}}-\d{{2}}", TIMELINE_PATTERNS.join("|"))).unwrap()
+});
+
+/// An owner label ("owner:", "assigned to") and the name after it.
+static OWNER_NAME_RE: LazyLock<Regex> = LazyLock::new(|| {
+    let labels: Vec<String> = OWNER_PATTERNS.iter().map(|p| regex::escape(p)).collect();
+    Regex::new(&format!(r"(?i:{})\s*([A-Za-z][\w.'-]*(?:\s+[A-Z][\w.'-]*)?)", labels.join("|"))).unwrap()
+});
+
+/// Phrases with a whole-word pattern for each.
+type PhrasePatterns = Vec<(&'static str, Regex)>;
+
+/// `CONTRADICTION_PAIRS` as whole-word patterns over lower-cased text.
+static CONTRADICTION_PATTERNS: LazyLock<Vec<(PhrasePatterns, PhrasePatterns)>> = LazyLock::new(|| {
+    let compile = |phrases: &[&'static str]| -> PhrasePatterns {
+        phrases
+            .iter()
+            .map(|p| (*p, Regex::new(&format!(r"\b{}\b", regex::escape(p))).unwrap()))
+            .collect()
+    };
+    CONTRADICTION_PAIRS
+        .iter()
+        .map(|(claims, counters)| (compile(claims), compile(counters)))
+        .collect()
+});
+
+/// An assumption's status cue: a leading checkbox or a trailing `(validated)`.
+static ASSUMPTION_CUE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^\[( |x)\]\s*|\s*\((validated|confirmed|pending|open|invalidated|disproven)\)\s*$")
+        .unwrap()
+});
+
+/// What `redact_pii` looks for, with the placeholder for each.
+static PII_PATTERNS: LazyLock<Vec<(PiiKind, Regex, &'static str)>> = LazyLock::new(|| {
+    [
+        (PiiKind::Email, r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}", "[EMAIL]"),
+        (PiiKind::Phone, r"(?:\+?\d{1,3}[\s.-]?)?\(?\d{3}\)?[\s.-]\d{3}[\s.-]\d{4}\b", "[PHONE]"),
+        (PiiKind::Name, r"\b(?:Mr|Mrs|Ms|Dr|Prof)\.?\s+[A-Z][a-z]+(?:\s+[A-Z][a-z]+)?", "[NAME]"),
+        (PiiKind::Name, r"(?i:owner|assigned to|lead|responsible|contact):\s*[A-Z][a-z]+\s+[A-Z][a-z]+", "[NAME]"),
+    ]
+    .into_iter()
+    .map(|(kind, pattern, placeholder)| (kind, Regex::new(pattern).unwrap(), placeholder))
+    .collect()
+});
+
+/// A duration: a number or number word, then a day-to-year unit.
+static DURATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(\d+(?:\.\d+)?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\s*-?\s*(day|week|month|quarter|year)s?\b",
+    )
+    .unwrap()
+});
+
+fn clean_model_text(s: &str) -> String {
+    clean_lines(s.lines())
+}
+
+/// Strip code fences, Markdown heading markers, horizontal rules and
+/// trailing whitespace one line at a time, then trim the whole text.
+fn clean_lines<I, S>(lines: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut out = String::new();
+    for line in lines {
+        push_cleaned_line(&mut out, &clean_line(line.as_ref()));
+    }
+    out.truncate(out.trim_end().len());
+    out
+}
+
+fn clean_line(line: &str) -> String {
+    let line = line.replace("```", "");
+    let line = MD_HEADING_RE.replace(&line, "");
+    if RULE_LINE_RE.is_match(&line) {
+        String::new()
+    } else {
+        line.trim_end().to_string()
+    }
+}
+
+fn push_cleaned_line(out: &mut String, line: &str) {
+    if out.is_empty() {
+        // Leading blank lines and indentation are trimmed away
+        out.push_str(line.trim_start());
+    } else {
+        out.push('\n');
+        out.push_str(line);
+    }
+}
+
+/// Rewrite near-miss header lines to the required header they are within
+/// `max_distance` edits of, so every later pass sees the canonical name.
+/// Only header-like lines (ending in `:` or all caps) are considered, and
+/// headers already present verbatim are left alone.
+fn fuzzy_match_headers(
+    cleaned: &str,
+    headers: &[String],
+    max_distance: usize,
+) -> Result<(String, Vec<String>), ScoreError> {
+    let line_re = &*FUZZY_HEADER_RE;
+    let (missing, _, _, _) = evaluate_headers(&scan_headers(cleaned.lines(), headers)?, 0);
+
+    let mut notes: Vec<String> = Vec::new();
+    let lines: Vec<String> = cleaned
+        .lines()
+        .map(|line| {
+            let Some(c) = line_re.captures(line) else {
+                return line.to_string();
+            };
+            let found = c[1].to_uppercase();
+            if c[2].is_empty() && found != c[1] {
+                return line.to_string();
+            }
+            let closest = missing
+                .iter()
+                .map(|h| (h, levenshtein(&found, &h.to_uppercase())))
+                .filter(|(_, d)| *d > 0 && *d <= max_distance)
+                .min_by_key(|(_, d)| *d);
+            match closest {
+                Some((h, d)) => {
+                    notes.push(format!("Matched '{}' to '{}' (distance {})", c[1].trim(), h, d));
+                    format!("{}:", h)
+                }
+                None => line.to_string(),
+            }
+        })
+        .collect();
+
+    Ok((lines.join("\n"), notes))
+}
+
+/// Edit distance between two strings, by character.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+/// Blank out quoted lines: `>` blockquotes (including lazy continuation lines
+/// up to the next blank line) and text between opening/closing quotation marks.
+fn strip_quoted_lines(s: &str) -> String {
+    let mut out: Vec<&str> = Vec::new();
+    let mut in_blockquote = false;
+    let mut in_quotation = false;
+
+    for line in s.lines() {
+        let t = line.trim();
+
+        if t.is_empty() {
+            in_blockquote = false;
+            out.push(line);
+            continue;
+        }
+
+        if in_quotation {
+            in_quotation = !(t.ends_with('"') || t.ends_with('”'));
+            out.push("");
+            continue;
+        }
+
+        if t.starts_with('>') || in_blockquote {
+            in_blockquote = true;
+            out.push("");
+            continue;
+        }
+
+        if let Some(rest) = t.strip_prefix('"').or_else(|| t.strip_prefix('“')) {
+            in_quotation = !(rest.ends_with('"') || rest.ends_with('”'));
+            out.push("");
+            continue;
+        }
+
+        out.push(line);
+    }
+
+    out.join("\n")
+}
+
+/// One line as header detection sees it: bullets and dashes unified,
+/// `HEADER :` tightened to `HEADER:`, upper-cased.
+fn normalize_header_line(line: &str) -> String {
+    let line = line.replace("•", "- ").replace("–", "- ").replace("—", "- ");
+    COLON_HEADER_RE.replace(&line, "$1:").to_uppercase()
+}
+
+/// What one pass over a report's lines learns about its required sections,
+/// so header checks never need an upper-cased copy of the whole report.
+#[derive(Debug, Clone)]
+struct HeaderScan {
+    required: Vec<String>,
+    /// Line of each required header's first occurrence.
+    first_line: Vec<Option<usize>>,
+    occurrences: Vec<usize>,
+    /// Body under each required header's first occurrence.
+    bodies: Vec<SectionBody>,
+    /// Upper-cased NEXT ACTIONS body, up to the next header-like line.
+    next_actions: Option<String>,
+}
+
+/// Running counts for one section body, fed a normalized line at a time.
+#[derive(Debug, Clone, Default)]
+struct SectionBody {
+    content_chars: usize,
+    first_char: Option<char>,
+    has_list_item: bool,
+    /// `[A-Z0-9]{2,}` runs, the loose word test for emptiness.
+    header_words: usize,
+    /// Whitespace-separated words with a letter; list markers don't count.
+    words: usize,
+    /// The body's lines as written, for `ScoreResult::sections`.
+    text: String,
+    /// Clarity of `text`, filled in once a later header closes the body.
+    readability: Option<f64>,
+}
+
+impl SectionBody {
+    /// Add one body line, as written and as `normalize_header_line` sees it.
+    fn add(&mut self, raw: &str, line: &str) {
+        self.text.push_str(raw);
+        self.text.push('\n');
+        let mut content = line.chars().filter(|c| !c.is_whitespace());
+        if self.first_char.is_none() {
+            self.first_char = content.next();
+            self.content_chars += self.first_char.is_some() as usize;
+        }
+        self.content_chars += content.count();
+        self.has_list_item |= BULLET_RE.is_match(line) || NUMBERED_RE.is_match(line);
+        self.header_words += HEADER_WORD_RE.find_iter(line).count();
+        self.words += line
+            .split_whitespace()
+            .filter(|w| w.chars().any(|c| c.is_alphabetic()))
+            .count();
+    }
+
+    /// Nothing but whitespace, or a lone colon left over from the header.
+    fn is_blank(&self) -> bool {
+        self.content_chars == 0 || (self.content_chars == 1 && self.first_char == Some(':'))
+    }
+
+    fn readability(&self, run_on_words: usize) -> f64 {
+        self.readability.unwrap_or_else(|| {
+            let body = self.text.trim();
+            calculate_clarity_score(body, count_run_on_sentences(body, run_on_words))
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum NextActionsState {
+    Before,
+    Reading(String),
+    Done,
+}
+
+/// Builds a `HeaderScan` from lines as they arrive.
+#[derive(Debug, Clone)]
+struct HeaderScanner {
+    scan: HeaderScan,
+    upper: Vec<String>,
+    /// Matches a line holding any required header; `None` when there are none.
+    header_re: Option<Regex>,
+    /// Ends NEXT ACTIONS: another required header or any `LABEL:` line.
+    stop_re: Regex,
+    line: usize,
+    /// Headers whose first-occurrence body is being read.
+    current: Vec<usize>,
+    next_actions: NextActionsState,
+    /// Run-on threshold for scoring bodies as they close; `None` skips it.
+    readability_words: Option<usize>,
+}
+
+impl HeaderScanner {
+    fn new<S: AsRef<str>>(required: &[S]) -> Result<Self, ScoreError> {
+        let upper: Vec<String> = required.iter().map(|h| h.as_ref().to_uppercase()).collect();
+        let header_re = if upper.is_empty() {
+            None
+        } else {
+            let alternatives: Vec<String> = upper.iter().map(|h| regex::escape(h)).collect();
+            Some(cached_pattern(&format!(r"^\s*({})\s*:?\s*$", alternatives.join("|")))?)
+        };
+
+        let others: Vec<String> = upper
+            .iter()
+            .filter(|h| h.as_str() != "NEXT ACTIONS")
+            .map(|h| regex::escape(h))
+            .collect();
+        let mut stops = vec![r"[A-Z][A-Z0-9 &/\-]{2,40}:".to_string()];
+        if !others.is_empty() {
+            stops.push(format!(r"(?:{})\s*:?", others.join("|")));
+        }
+        let stop_re = cached_pattern(&format!(r"^\s*(?:{})\s*$", stops.join("|")))?;
+
+        let n = required.len();
+        Ok(Self {
+            scan: HeaderScan {
+                required: required.iter().map(|h| h.as_ref().to_string()).collect(),
+                first_line: vec![None; n],
+                occurrences: vec![0; n],
+                bodies: vec![SectionBody::default(); n],
+                next_actions: None,
+            },
+            upper,
+            header_re,
+            stop_re,
+            line: 0,
+            current: Vec::new(),
+            next_actions: NextActionsState::Before,
+            readability_words: None,
+        })
+    }
+
+    /// Score each body's readability as soon as the next header closes it,
+    /// so a scanner that keeps growing never re-reads a finished section.
+    fn with_readability(mut self, run_on_words: usize) -> Self {
+        self.readability_words = Some(run_on_words);
+        self
+    }
+
+    /// Feed the next cleaned line.
+    fn push(&mut self, line: &str) {
+        let norm = normalize_header_line(line);
+
+        self.next_actions = match std::mem::replace(&mut self.next_actions, NextActionsState::Done) {
+            NextActionsState::Before if NEXT_ACTIONS_RE.is_match(&norm) => NextActionsState::Reading(String::new()),
+            NextActionsState::Reading(body) if self.stop_re.is_match(&norm) => {
+                self.scan.next_actions = Some(body);
+                NextActionsState::Done
+            }
+            NextActionsState::Reading(mut body) => {
+                body.push_str(&norm);
+                body.push('\n');
+                NextActionsState::Reading(body)
+            }
+            state => state,
+        };
+
+        let matched = self.header_re.as_ref().and_then(|re| re.captures(&norm));
+        match matched {
+            Some(c) => {
+                if let Some(words) = self.readability_words {
+                    for &i in &self.current {
+                        let body = &mut self.scan.bodies[i];
+                        body.readability = Some(body.readability(words));
+                    }
+                }
+                self.current.clear();
+                for (i, h) in self.upper.iter().enumerate() {
+                    if *h != c[1] {
+                        continue;
+                    }
+                    self.scan.occurrences[i] += 1;
+                    if self.scan.first_line[i].is_none() {
+                        self.scan.first_line[i] = Some(self.line);
+                        self.current.push(i);
+                    }
+                }
+            }
+            None => {
+                for &i in &self.current {
+                    self.scan.bodies[i].add(line, &norm);
+                }
+            }
+        }
+        self.line += 1;
+    }
+
+    fn finish(mut self) -> HeaderScan {
+        if let NextActionsState::Reading(body) = self.next_actions {
+            self.scan.next_actions = Some(body);
+        }
+        self.scan
+    }
+}
+
+fn scan_headers<'a, S: AsRef<str>>(
+    lines: impl IntoIterator<Item = &'a str>,
+    required: &[S],
+) -> Result<HeaderScan, ScoreError> {
+    let mut scanner = HeaderScanner::new(required)?;
+    for line in lines {
+        scanner.push(line);
+    }
+    Ok(scanner.finish())
+}
+
+/// Present headers that break `required` order: everything outside the
+/// longest run already in order, so one moved section flags only itself.
+fn misordered_headers(scan: &HeaderScan) -> Vec<String> {
+    let found: Vec<(&str, usize)> = scan
+        .required
+        .iter()
+        .zip(&scan.first_line)
+        .filter_map(|(h, line)| line.map(|l| (h.as_str(), l)))
+        .collect();
+
+    // Longest increasing subsequence of offsets, O(n^2) over a handful of headers
+    let n = found.len();
+    let mut best = vec![1usize; n];
+    let mut prev: Vec<Option<usize>> = vec![None; n];
+    for i in 0..n {
+        for j in 0..i {
+            if found[j].1 < found[i].1 && best[j] + 1 > best[i] {
+                best[i] = best[j] + 1;
+                prev[i] = Some(j);
+            }
+        }
+    }
+    let mut in_order = vec![false; n];
+    let mut cursor = (0..n).max_by_key(|&i| (best[i], std::cmp::Reverse(i)));
+    while let Some(i) = cursor {
+        in_order[i] = true;
+        cursor = prev[i];
+    }
+
+    found
+        .iter()
+        .zip(in_order)
+        .filter(|(_, ok)| !ok)
+        .map(|((h, _), _)| h.to_string())
+        .collect()
+}
+
+/// Header problems found by `evaluate_headers`.
+type HeaderFindings = (Vec<String>, Vec<String>, Vec<String>, Vec<String>);
+
+/// (missing, duplicate, empty, thin) headers. A present, non-empty section
+/// with fewer than `min_words` words is thin; 0 disables the check.
+fn evaluate_headers(scan: &HeaderScan, min_words: usize) -> HeaderFindings {
+    let mut missing: Vec<String> = Vec::new();
+    let mut dupes: Vec<String> = Vec::new();
+    let mut empty: Vec<String> = Vec::new();
+    let mut thin: Vec<String> = Vec::new();
+
+    for (i, h) in scan.required.iter().enumerate() {
+        if scan.first_line[i].is_none() {
+            missing.push(h.clone());
+            continue;
+        }
+        if scan.occurrences[i] > 1 {
+            dupes.push(h.clone());
+        }
+
+        let body = &scan.bodies[i];
+        if body.is_blank() || (!body.has_list_item && body.header_words < 1) {
+            empty.push(h.clone());
+            continue;
+        }
+        if body.words < min_words {
+            thin.push(h.clone());
+        }
+    }
+
+    (missing, dupes, empty, thin)
+}
+
+fn count_next_actions(scan: &HeaderScan) -> usize {
+    let section = match &scan.next_actions {
+        Some(body) => body.trim(),
+        None => return 0,
+    };
+    if section.is_empty() {
+        return 0;
+    }
+
+    let bullet_re = &*BULLET_RE;
+    let num_re = &*NUMBERED_RE;
+
+    let bullets = bullet_re.find_iter(section).count();
+    let nums = num_re.find_iter(section).count();
+    let table_rows = parse_table(section).map_or(0, |(_, rows)| rows.len());
+
+    bullets.max(nums).max(table_rows)
+}
+
+/// Words a last line needs before a missing full stop reads as a cut-off
+/// sentence rather than a short label ("90 days").
+const INCOMPLETE_SENTENCE_MIN_WORDS: usize = 4;
+
+fn looks_truncated(cleaned: &str, check_sentences: bool) -> bool {
+    let t = cleaned.trim_end();
+
+    if t.is_empty() {
+        return true;
+    }
+
+    let bad_endings = ["...", "…", "```", "**", "__", "- ", "* ", "1.", "2.", "3."];
+    if bad_endings.iter().any(|x| t.ends_with(x)) {
+        return true;
+    }
+
+    if t.ends_with('(') || t.ends_with(':') || t.ends_with(',') {
+        return true;
+    }
+
+    let lines: Vec<&str> = t.lines().collect();
+    if lines.len() >= 10 {
+        if let Some(last) = lines.last() {
+            if last.trim().len() <= 3 {
+                return true;
+            }
+        }
+    }
+
+    check_sentences && lines.last().is_some_and(|last| is_unfinished_sentence(last.trim()))
+}
+
+/// A prose line of several words that stops without terminal punctuation.
+/// Headers, list items and table rows are not sentences.
+fn is_unfinished_sentence(line: &str) -> bool {
+    if line.split_whitespace().count() < INCOMPLETE_SENTENCE_MIN_WORDS
+        || HEADER_LINE_RE.is_match(line)
+        || LIST_ITEM_RE.is_match(line)
+        || line.starts_with('|')
+    {
+        return false;
+    }
+    let end = line.trim_end_matches(['"', '\'', '”', '’', ')', ']', '*', '_']);
+    !end.ends_with(['.', '!', '?', ';'])
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_scoring() {
+        let input = r#"
+BEST OPTION:
+Choose Option A for maximum ROI.
+
+RATIONALE:
+- Cost effective
+- Proven technology
+- Team expertise
+
+TOP RISKS:
+- Market volatility
+- Technical debt
+- Resource constraints
+
+ASSUMPTIONS TO VALIDATE:
+- Budget approved
+- Team available
+- Timeline feasible
+
+HALF-LIFE:
+6 months - review quarterly
+
+BLIND SPOTS:
+- Competitor moves
+- Regulatory changes
+
+NEXT ACTIONS:
+1. Get budget approval by Friday
+2. Schedule kickoff meeting
+3. Assign project lead
+4. Create project charter
+5. Set up tracking
+6. Send stakeholder update
+"#;
+
+        let result = score_report_text(input, ScoringConfig::default());
+        assert!(result.score >= 80);
+        assert!(!result.must_repair);
+        assert_eq!(result.missing_headers.len(), 0);
+    }
+
+    #[test]
+    fn test_min_floor_score() {
+        // All headers present but every section empty, no actions, truncated.
+        let input = "BEST OPTION:\nRATIONALE:\nTOP RISKS:\nASSUMPTIONS TO VALIDATE:\nHALF-LIFE:\nBLIND SPOTS:\nNEXT ACTIONS:";
+
+        let raw = score_report_text(input, ScoringConfig::default());
+        assert!(raw.score < 20);
+
+        let cfg = ScoringConfig {
+            min_floor_score: 20,
+            ..ScoringConfig::default()
+        };
+        let floored = score_report_text(input, cfg);
+        assert_eq!(floored.score, 20);
+        assert!(floored.notes.iter().any(|n| n.starts_with("Score floored at 20")));
+
+        // Nothing salvageable: the floor does not apply.
+        let cfg = ScoringConfig {
+            min_floor_score: 20,
+            ..ScoringConfig::default()
+        };
+        assert_eq!(score_report_text("just some text...", cfg).score, 0);
+    }
+
+    #[test]
+    fn test_monte_carlo() {
+        let risks = vec![
+            RiskFactor {
+                name: "Market Risk".to_string(),
+                probability: 0.3,
+                impact_low: 5.0,
+                impact_high: 15.0,
+                category: RiskCategory::Market,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+            RiskFactor {
+                name: "Technical Risk".to_string(),
+                probability: 0.2,
+                impact_low: 10.0,
+                impact_high: 25.0,
+                category: RiskCategory::Technical,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+        ];
+
+        let result = run_monte_carlo_simulation(
+            85.0,
+            &risks,
+            MonteCarloConfig {
+                iterations: 1000,
+                seed: Some(42),
+                confidence_level: 0.95,
+                detect_modality: false,
+                track_contributions: false,
+                failure_thresholds: vec![],
+                regime_priors: vec![],
+                correlation_matrix: None,
+                histogram_bins: 20,
+                parallel: false,
+            },
+        );
+
+        assert!(result.mean_score > 70.0 && result.mean_score < 90.0);
+        assert!(result.std_dev > 0.0);
+        assert_eq!(result.iterations_run, 1000);
+    }
+
+    #[test]
+    fn test_sensitivity_analysis() {
+        let config = SensitivityConfig {
+            variables: vec![
+                SensitivityVariable {
+                    name: "Budget".to_string(),
+                    base_value: 100000.0,
+                    min_value: 50000.0,
+                    max_value: 150000.0,
+                    weight: 0.8,
+                },
+                SensitivityVariable {
+                    name: "Timeline".to_string(),
+                    base_value: 90.0,
+                    min_value: 60.0,
+                    max_value: 120.0,
+                    weight: 0.5,
+                },
+            ],
+            step_count: 10,
+        };
+
+        let result = run_sensitivity_analysis(80.0, config);
+        
+        assert_eq!(result.variable_impacts.len(), 2);
+        assert_eq!(result.tornado_chart_data.len(), 2);
+    }
+
+    #[test]
+    fn test_decision_decay() {
+        let config = DecisionDecayConfig {
+            initial_confidence: 90.0,
+            decay_factors: vec![
+                DecayFactor {
+                    name: "Market Changes".to_string(),
+                    decay_rate: 0.5,
+                    volatility: 0.2,
+                },
+            ],
+            time_horizon_days: 365,
+            start_date: None,
+            half_life_tolerance: 2.0,
+            shock_events: vec![],
+            decay_model: DecayModel::Exponential,
+        };
+
+        let result = calculate_decision_decay(config);
+        
+        assert!(result.half_life_days > 0.0);
+        assert!(!result.confidence_timeline.is_empty());
+        assert!(result.stability_score >= 0.0 && result.stability_score <= 100.0);
+    }
+
+    #[test]
+    fn test_agreement() {
+        let model = [92, 80, 65, 45, 30];
+        let human = [90, 84, 60, 50, 30];
+
+        let result = agreement(&model, &human).unwrap();
+        assert_eq!(result.sample_count, 5);
+        assert!((result.mean_absolute_error - 3.2).abs() < 1e-9);
+        assert!(result.pearson_correlation > 0.98);
+        assert!((result.cohens_kappa - 1.0).abs() < 1e-9);
+
+        assert!(agreement(&model, &human[..4]).is_err());
+    }
+
+    #[test]
+    fn test_lexicon_registry() {
+        let text = "Algunos clientes podrían cambiar. Quizás muchos proveedores suban precios; \
+                    generalmente la demanda es variable y a veces cae para varios productos.";
+
+        let registry = LexiconRegistry::default();
+        assert_eq!(registry.guess_language(text).as_deref(), Some("es"));
+        assert_eq!(registry.detect_or_use(None, text).language, "es");
+        assert_eq!(registry.detect_or_use(Some("de"), text).language, "de");
+
+        let en = calculate_specificity_score(text, &Lexicon::english());
+        let es = calculate_specificity_score(text, &Lexicon::spanish());
+        assert!(es < en);
+    }
+
+    #[test]
+    fn test_next_action_marginal_value() {
+        let cfg = ScoringConfig::default();
+        let values: Vec<i32> = (0..=7).map(|n| next_action_marginal_value(n, &cfg)).collect();
+        // +3 per action, then the flat penalty is lifted on reaching 6.
+        assert_eq!(values, vec![3, 3, 3, 3, 3, 13, 0, 0]);
+    }
+
+    #[test]
+    fn test_redact_pii() {
+        let text = "Contact jane.doe@example.com or call 555-123-4567 before Friday.";
+        let (redacted, redactions) = redact_pii(text);
+
+        assert_eq!(redacted, "Contact [EMAIL] or call [PHONE] before Friday.");
+        assert_eq!(redactions.len(), 2);
+        assert_eq!(redactions[0].kind, PiiKind::Email);
+        assert_eq!(&text[redactions[0].start..redactions[0].end], "jane.doe@example.com");
+        assert_eq!(redactions[1].kind, PiiKind::Phone);
+        assert_eq!(&text[redactions[1].start..redactions[1].end], "555-123-4567");
+
+        let (redacted, _) = redact_pii("Owner: Jane Doe, reviewed by Dr. Smith");
+        assert_eq!(redacted, "Owner: [NAME], reviewed by [NAME]");
+    }
+
+    #[test]
+    fn test_monte_carlo_modality() {
+        // Coin-flip disaster: outcomes cluster near 90 and near 37.
+        let risks = vec![RiskFactor {
+            name: "Contract Loss".to_string(),
+            probability: 0.5,
+            impact_low: 50.0,
+            impact_high: 56.0,
+            category: RiskCategory::Financial,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        }];
+
+        let config = MonteCarloConfig {
+            iterations: 2000,
+            seed: Some(7),
+            detect_modality: true,
+            ..MonteCarloConfig::default()
+        };
+        let result = run_monte_carlo_simulation(90.0, &risks, config);
+        assert_eq!(result.modality, Some(Modality::Bimodal));
+
+        let result = run_monte_carlo_simulation(90.0, &risks, MonteCarloConfig::default());
+        assert_eq!(result.modality, None);
+    }
+
+    #[test]
+    fn test_ignore_quoted_headers() {
+        let input = r#"
+BEST OPTION:
+Follow the template guidance below.
+
+> Make sure you include a section titled
+NEXT ACTIONS:
+- with at least six items
+
+RATIONALE:
+- It matches the brief.
+"#;
+
+        let cfg = ScoringConfig::default();
+        let result = score_report_text(input, cfg);
+        assert!(!result.missing_headers.contains(&"NEXT ACTIONS".to_string()));
+
+        let cfg = ScoringConfig {
+            ignore_quoted_headers: true,
+            ..ScoringConfig::default()
+        };
+        let result = score_report_text(input, cfg);
+        assert!(result.missing_headers.contains(&"NEXT ACTIONS".to_string()));
+        assert_eq!(result.next_actions_count, 0);
+        // Sections agree with the scan: no body for the ignored header
+        assert!(!result.sections.contains_key("NEXT ACTIONS"));
+        assert!(result.sections["BEST OPTION"].starts_with("Follow the template guidance below."));
+        assert_eq!(result.sections["RATIONALE"], "- It matches the brief.");
+
+        let quoted = "BEST OPTION:\nGo.\n\n\"Always end with\nNEXT ACTIONS:\n- a list\"\n";
+        let cfg = ScoringConfig {
+            ignore_quoted_headers: true,
+            ..ScoringConfig::default()
+        };
+        let result = score_report_text(quoted, cfg);
+        assert!(result.missing_headers.contains(&"NEXT ACTIONS".to_string()));
+        assert!(!result.sections.contains_key("NEXT ACTIONS"));
+    }
+
+    #[test]
+    fn test_score_waterfall() {
+        let input = "BEST OPTION:\nShip it.\n\nNEXT ACTIONS:\n- Draft plan\n- Book review";
+        let result = score_report_text(input, ScoringConfig::default());
+        let steps = score_waterfall(&result);
+
+        assert_eq!(steps.first().unwrap().running_total, 100);
+        assert_eq!(steps.last().unwrap().running_total, result.score as i32);
+        assert_eq!(steps.len(), result.penalties.len() + 1);
+    }
+
+    #[test]
+    fn test_interval_coverage() {
+        let risks = vec![RiskFactor {
+            name: "Scope Creep".to_string(),
+            probability: 0.6,
+            impact_low: 10.0,
+            impact_high: 40.0,
+            category: RiskCategory::Operational,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        }];
+        let config = MonteCarloConfig {
+            iterations: 2000,
+            seed: Some(11),
+            histogram_bins: 100,
+            ..MonteCarloConfig::default()
+        };
+        let mc = run_monte_carlo_simulation(85.0, &risks, config);
+
+        // A +/-3 point interval around the base score misses most risk outcomes.
+        let narrow = ConfidenceInterval {
+            lower_bound: 82.0,
+            upper_bound: 88.0,
+            confidence_level: 0.95,
+        };
+        let coverage = interval_coverage(&narrow, &mc).unwrap();
+        assert!(coverage < narrow.confidence_level);
+        assert!(coverage > 0.3 && coverage < 0.5);
+
+        let full = ConfidenceInterval {
+            lower_bound: 0.0,
+            upper_bound: 100.0,
+            confidence_level: 0.95,
+        };
+        assert!((interval_coverage(&full, &mc).unwrap() - 1.0).abs() < 1e-9);
+
+        // No risks: every outcome is the base score, one zero-width bin
+        let flat = run_monte_carlo_simulation(85.0, &[], MonteCarloConfig { iterations: 100, ..MonteCarloConfig::default() });
+        assert_eq!(interval_coverage(&narrow, &flat), Some(1.0));
+        let below = ConfidenceInterval { lower_bound: 50.0, upper_bound: 80.0, confidence_level: 0.95 };
+        assert_eq!(interval_coverage(&below, &flat), Some(0.0));
+
+        // No histogram: unknown, not zero coverage
+        let unbinned = run_monte_carlo_simulation(
+            85.0,
+            &risks,
+            MonteCarloConfig { iterations: 100, histogram_bins: 0, ..MonteCarloConfig::default() },
+        );
+        assert_eq!(interval_coverage(&full, &unbinned), None);
+    }
+
+    #[test]
+    fn test_run_on_sentence_penalty() {
+        let words = |n: usize| vec!["word"; n].join(" ");
+
+        // Both average 18 words per sentence; only the first has a run-on.
+        let with_run_on = format!("{}. {}. {}. {}.", words(60), words(4), words(4), words(4));
+        let even = format!("{}. {}. {}. {}.", words(18), words(18), words(18), words(18));
+
+        assert_eq!(count_run_on_sentences(&with_run_on, 40), 1);
+        assert_eq!(count_run_on_sentences(&even, 40), 0);
+
+        let a = calculate_clarity_score(&with_run_on, count_run_on_sentences(&with_run_on, 40));
+        let b = calculate_clarity_score(&even, count_run_on_sentences(&even, 40));
+        assert!(a < b);
+    }
+
+    #[test]
+    fn test_decision_debt() {
+        // 1.0%/day decay -> half-life of ~69 days.
+        let config = DecisionDecayConfig {
+            initial_confidence: 80.0,
+            decay_factors: vec![DecayFactor {
+                name: "Market".to_string(),
+                decay_rate: 1.0,
+                volatility: 0.1,
+            }],
+            time_horizon_days: 365,
+            start_date: Some("2026-01-01".to_string()),
+            half_life_tolerance: 2.0,
+            shock_events: vec![],
+            decay_model: DecayModel::Exponential,
+        };
+
+        let fresh = decision_debt(&config, "2026-01-20").unwrap();
+        assert_eq!(fresh.level, DebtLevel::None);
+
+        // Two half-lives later
+        let stale = decision_debt(&config, "2026-05-20T09:00:00Z").unwrap();
+        assert_eq!(stale.days_since_decision, 139);
+        assert_eq!(stale.level, DebtLevel::High);
+        assert!(stale.days_overdue > 100.0);
+        assert!(stale.confidence_erosion > 0.0);
+
+        assert_eq!(format_iso_date(parse_iso_date("2024-02-29").unwrap()), "2024-02-29");
+        assert_eq!(parse_iso_date("2024-02-31"), None);
+        assert_eq!(parse_iso_date("2023-02-29"), None);
+        assert_eq!(parse_iso_date("2024-04-31"), None);
+        assert_eq!(
+            decision_debt(&config, "not a date").unwrap_err(),
+            DecisionDebtError::InvalidDate("not a date".to_string())
+        );
+        let undated = DecisionDecayConfig { start_date: None, ..config.clone() };
+        assert_eq!(decision_debt(&undated, "2026-01-20").unwrap_err(), DecisionDebtError::MissingStartDate);
+
+        // No factors, no decay: never due for review
+        let no_factors = DecisionDecayConfig { decay_factors: vec![], ..config.clone() };
+        assert!(!calculate_decision_decay(no_factors.clone()).half_life_days.is_nan());
+        let debt = decision_debt(&no_factors, "2027-01-01").unwrap();
+        assert!(!debt.critical_review_day.is_nan());
+        assert_eq!(debt.level, DebtLevel::None);
+        assert_eq!(debt.confidence_erosion, 0.0);
+
+        // Erosion follows the configured model and shocks, not a fixed exponential
+        let shocked = DecisionDecayConfig {
+            decay_model: DecayModel::Linear,
+            shock_events: vec![ShockEvent { day: 10, confidence_drop: 0.5 }],
+            ..config
+        };
+        let curve = calculate_decision_decay(shocked.clone()).confidence_timeline;
+        let debt = decision_debt(&shocked, "2026-01-31").unwrap();
+        assert_eq!(debt.days_since_decision, 30);
+        assert!((debt.confidence_erosion - (curve[5].confidence - curve[30].confidence)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_summary_line() {
+        let mut result = score_report_text("BEST OPTION:\nGo.", ScoringConfig::default());
+        result.score = 72;
+        result.missing_headers = vec!["BLIND SPOTS".to_string()];
+        result.empty_sections.clear();
+        result.duplicate_headers.clear();
+        result.next_actions_count = 4;
+        result.next_actions_ok = false;
+        result.truncation_suspected = false;
+        assert_eq!(
+            summary_line(&result),
+            "[72/100 B] missing=BLIND SPOTS actions=4/6 trunc=no"
+        );
+
+        result.score = 95;
+        result.missing_headers.clear();
+        result.next_actions_count = 6;
+        result.next_actions_ok = true;
+        assert_eq!(summary_line(&result), "[95/100 A] ok");
+    }
+
+    #[test]
+    fn test_weighted_specific_patterns() {
+        let lexicon = Lexicon::english();
+        let budget = calculate_specificity_score("Approve the migration budget of $40,000 now.", &lexicon);
+        let clock = calculate_specificity_score("Approve the migration budget at 3:00 today.", &lexicon);
+        assert!(budget > clock);
+    }
+
+    #[test]
+    fn test_risk_contribution_ranks() {
+        let risk = |name: &str, probability: f64, impact: f64| RiskFactor {
+            name: name.to_string(),
+            probability,
+            impact_low: impact,
+            impact_high: impact,
+            category: RiskCategory::Operational,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        };
+        let risks = vec![
+            risk("Minor", 0.1, 2.0),
+            risk("Dominant", 0.5, 20.0),
+            risk("Middle", 0.3, 8.0),
+        ];
+
+        let config = MonteCarloConfig {
+            iterations: 2000,
+            seed: Some(3),
+            track_contributions: true,
+            ..MonteCarloConfig::default()
+        };
+        let result = run_monte_carlo_simulation(90.0, &risks, config);
+        let total_share: f64 = result.risk_contributions.iter().map(|c| c.share).sum();
+        assert!((total_share - 1.0).abs() < 1e-9);
+
+        let ranks: HashMap<String, f64> = risk_contribution_ranks(&result).into_iter().collect();
+        assert!((ranks["Dominant"] - 1.0).abs() < 1e-9);
+        assert!(ranks["Minor"] < 0.1);
+        assert!((ranks["Middle"] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_draft_mode_deferred_headers() {
+        let input = r#"
+BEST OPTION:
+Option A.
+
+RATIONALE:
+- Cheapest path
+
+TOP RISKS:
+- Vendor lock-in
+
+ASSUMPTIONS TO VALIDATE:
+- Budget holds
+
+BLIND SPOTS:
+- Competitor response
+
+NEXT ACTIONS:
+1. Draft plan
+2. Book review
+3. Confirm budget
+4. Assign owner
+5. Notify team
+6. Set checkpoint
+"#;
+
+        let cfg = ScoringConfig {
+            draft_mode: true,
+            allowed_missing: vec!["HALF-LIFE".to_string()],
+            ..ScoringConfig::default()
+        };
+        let result = score_report_text(input, cfg);
+        assert_eq!(result.deferred_headers, vec!["HALF-LIFE".to_string()]);
+        assert!(result.missing_headers.is_empty());
+        assert_eq!(result.score, 100);
+        assert!(!result.must_repair);
+
+        let cfg = ScoringConfig {
+            draft_mode: true,
+            allowed_missing: vec!["BLIND SPOTS".to_string()],
+            ..ScoringConfig::default()
+        };
+        let result = score_report_text(input, cfg);
+        assert_eq!(result.missing_headers, vec!["HALF-LIFE".to_string()]);
+        assert_eq!(result.score, 88);
+        assert!(result.must_repair);
+
+        // Deferring NEXT ACTIONS also defers its count check
+        let draft = input.split("NEXT ACTIONS:").next().unwrap();
+        let cfg = ScoringConfig {
+            draft_mode: true,
+            allowed_missing: vec!["HALF-LIFE".to_string(), "NEXT ACTIONS".to_string()],
+            ..ScoringConfig::default()
+        };
+        let result = score_report_text(draft, cfg);
+        assert_eq!(result.deferred_headers, vec!["HALF-LIFE".to_string(), "NEXT ACTIONS".to_string()]);
+        assert!(result.next_actions_ok);
+        assert!(result.penalties.is_empty());
+        assert!(!result.must_repair);
+    }
+
+    #[test]
+    fn test_risk_materialization_stats() {
+        let risks: Vec<RiskFactor> = [0.5, 0.2, 0.1]
+            .iter()
+            .map(|&p| RiskFactor {
+                name: format!("Risk {}", p),
+                probability: p,
+                impact_low: 5.0,
+                impact_high: 10.0,
+                category: RiskCategory::Market,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            })
+            .collect();
+
+        assert!((expected_risks_materializing(&risks) - 0.8).abs() < 1e-9);
+
+        let stats = risk_materialization_stats(&risks);
+        assert!((stats.variance - (0.25 + 0.16 + 0.09)).abs() < 1e-9);
+        assert!((stats.probability_none - 0.5 * 0.8 * 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_custom_finish_reason_rules() {
+        let input = r#"
+BEST OPTION:
+Option A.
+
+RATIONALE:
+- Cheapest path
+
+TOP RISKS:
+- Vendor lock-in
+
+ASSUMPTIONS TO VALIDATE:
+- Budget holds
+
+HALF-LIFE:
+3 months
+
+BLIND SPOTS:
+- Competitor response
+
+RATIONALE:
+- Team already knows the stack
+
+NEXT ACTIONS:
+1. Draft plan
+2. Book review
+3. Confirm budget
+4. Assign owner
+5. Notify team
+6. Set checkpoint
+"#;
+
+        let result = score_report_text(input, ScoringConfig::default());
+        assert_eq!(result.duplicate_headers, vec!["RATIONALE".to_string()]);
+        assert_eq!(result.finish_reason_hint, "OK");
+
+        let mut rules = default_finish_reason_rules();
+        rules.insert(2, FinishReasonRule::new(HintCondition::DuplicateHeaders, "MINOR_ISSUES"));
+        let cfg = ScoringConfig {
+            finish_reason_rules: rules,
+            ..ScoringConfig::default()
+        };
+        let result = score_report_text(input, cfg);
+        assert_eq!(result.finish_reason_hint, "MINOR_ISSUES");
+    }
+
+    #[test]
+    fn test_risk_mitigation_coverage() {
+        let input = r#"
+BEST OPTION:
+Migrate to the managed database.
+
+TOP RISKS:
+- Data loss during cutover; mitigate with a dual-write window
+- Vendor price increase
+  - Fallback: negotiated 3-year price cap
+- Team unfamiliar with the new tooling
+
+NEXT ACTIONS:
+1. Schedule cutover
+"#;
+
+        let result = risk_mitigation_coverage(input);
+        assert_eq!(result.total_risks, 3);
+        assert_eq!(result.mitigated_risks, 2);
+        assert!((result.coverage - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(result.unpaired_risks, vec!["Team unfamiliar with the new tooling".to_string()]);
+    }
+
+    #[test]
+    fn test_incremental_scorer() {
+        let part1 = "BEST OPTION:\nOption A.\n\nRATIONALE:\n- Cheapest path\n\n";
+        let part2 = "NEXT ACTIONS:\n1. Draft plan\n2. Book review\n";
+
+        let mut scorer = IncrementalScorer::new(ScoringConfig::default());
+        scorer.append(part1);
+        let partial = scorer.current().score;
+        scorer.append(part2);
+
+        let one_shot = score_report_text(&format!("{}{}", part1, part2), ScoringConfig::default());
+        assert_eq!(scorer.current().score, one_shot.score);
+        assert_eq!(scorer.current().notes, one_shot.notes);
+        assert_ne!(partial, one_shot.score);
+    }
+
+    #[test]
+    fn test_incremental_scorer_split_lines() {
+        let report = "# Memo\n\nBEST OPTION:\nOption A, because it is the cheapest path and the team already knows it.\n\n\
+            RATIONALE:\n- Cheapest path\n- Lowest risk\n\nNEXT ACTIONS:\n1. Draft plan\n2. Book review\n3. Ship it";
+        let one_shot = score_report_text(report, ScoringConfig::default());
+
+        // Chunks that end mid-line and mid-header, read after every append
+        for size in [1, 5, 13, 40] {
+            let mut scorer = IncrementalScorer::new(ScoringConfig::default());
+            let chars: Vec<char> = report.chars().collect();
+            for chunk in chars.chunks(size) {
+                scorer.append(&chunk.iter().collect::<String>());
+                scorer.current();
+            }
+            let result = scorer.current();
+            assert_eq!(result.score, one_shot.score, "chunk size {}", size);
+            assert_eq!(result.notes, one_shot.notes);
+            assert_eq!(result.sections, one_shot.sections);
+            assert_eq!(result.section_readability, one_shot.section_readability);
+            assert_eq!(result.next_actions_count, one_shot.next_actions_count);
+            assert_eq!(result.composite_score, one_shot.composite_score);
+        }
+
+        // Configs the running scan can't serve still match
+        let cfg = ScoringConfig {
+            ignore_quoted_headers: true,
+            ..ScoringConfig::default()
+        };
+        let mut scorer = IncrementalScorer::new(cfg.clone());
+        scorer.append(&report[..30]);
+        scorer.append(&report[30..]);
+        assert_eq!(scorer.current().score, score_report_text(report, cfg).score);
+    }
+
+    #[test]
+    fn test_minimal_template_passes() {
+        let cfg = ScoringConfig::default();
+        let template = minimal_template(&cfg);
+        assert!(template.contains("[FILL IN:"));
+
+        let result = score_report_text(&template, cfg);
+        assert!(result.missing_headers.is_empty());
+        assert!(result.empty_sections.is_empty());
+        assert_eq!(result.next_actions_count, 6);
+        assert!(!result.must_repair);
+        assert_eq!(result.score, 100);
+    }
+
+    #[test]
+    fn test_recommendation() {
+        let cfg = ScoringConfig::default();
+
+        let clean = score_report_text(&minimal_template(&cfg), cfg.clone());
+        assert_eq!(recommendation(&clean, &cfg, None), Recommendation::Proceed);
+        assert_eq!(recommendation(&clean, &cfg, Some(0.2)), Recommendation::ProceedWithCaution);
+        assert_eq!(recommendation(&clean, &cfg, Some(0.5)), Recommendation::Revise);
+
+        let broken = score_report_text("BEST OPTION:\nMaybe...", cfg.clone());
+        assert!(broken.must_repair);
+        assert_eq!(recommendation(&broken, &cfg, None), Recommendation::Reject);
+    }
+
+    #[test]
+    fn test_structural_completeness() {
+        let cfg = ScoringConfig::default();
+        let full = minimal_template(&cfg);
+        assert!((score_report_text(&full, cfg.clone()).structural_completeness - 1.0).abs() < 1e-9);
+
+        let without_blind_spots = full.replace("BLIND SPOTS:", "");
+        let result = score_report_text(&without_blind_spots, cfg);
+        assert_eq!(result.missing_headers, vec!["BLIND SPOTS".to_string()]);
+        assert!((result.structural_completeness - 6.0 / 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_report_type_thresholds() {
+        let cfg = ScoringConfig {
+            required_headers: to_strings(&["BEST OPTION", "RATIONALE", "NEXT ACTIONS"]),
+            auto_detect_report_type: true,
+            ..ScoringConfig::default()
+        };
+
+        let lite = "BEST OPTION:\nRenew the vendor contract.\n\nRATIONALE:\n- Lowest switching cost\n\n\
+                    NEXT ACTIONS:\n1. Email vendor\n2. Sign renewal\n3. File contract";
+        assert_eq!(classify_report_type(lite), ReportType::Lite);
+        let result = score_report_text(lite, cfg.clone());
+        assert_eq!(result.next_actions_required, 3);
+        assert!(result.next_actions_ok);
+
+        let filler = "We reviewed the vendor landscape in detail. ".repeat(150);
+        let major = lite.replace("Lowest switching cost", &filler);
+        assert_eq!(classify_report_type(&major), ReportType::Major);
+        let result = score_report_text(&major, cfg.clone());
+        assert_eq!(result.next_actions_required, 8);
+        assert!(!result.next_actions_ok);
+
+        // Manual override wins over detection
+        let cfg = ScoringConfig {
+            report_type: Some(ReportType::Standard),
+            ..cfg
+        };
+        assert_eq!(score_report_text(lite, cfg).next_actions_required, 6);
+    }
+
+    #[test]
+    fn test_owner_concentration() {
+        let input = r#"
+NEXT ACTIONS:
+1. Draft migration plan (Owner: Priya)
+2. Book vendor review - Owner: Priya
+3. Confirm budget, owner: Priya
+4. Notify stakeholders (Owner: Priya)
+5. Set checkpoint. Assigned to Priya
+6. Update runbook (Owner: Sam Lee)
+7. Celebrate
+"#;
+
+        let result = owner_concentration(input, &ScoringConfig::default());
+        assert_eq!(result.total_actions, 7);
+        assert_eq!(result.owners[0], ("Priya".to_string(), 5));
+        assert_eq!(result.owners[1], ("Sam Lee".to_string(), 1));
+        assert_eq!(result.unowned_actions, 1);
+        assert_eq!(result.top_owner.as_deref(), Some("Priya"));
+        assert!(result.concentrated);
+    }
+
+    #[test]
+    fn test_composite_score() {
+        let input = minimal_template(&ScoringConfig::default());
+        let result = score_report_text(&input, ScoringConfig::default());
+        let expected = 0.7 * result.score as f64 + 0.3 * result.quality_metrics.overall_quality * 100.0;
+        assert!((result.composite_score - expected).abs() < 1e-9);
+
+        let cfg = ScoringConfig {
+            structural_weight: 1.0,
+            ..ScoringConfig::default()
+        };
+        let result = score_report_text(&input, cfg);
+        assert!((result.composite_score - result.score as f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_decay_sparkline_path() {
+        let config = DecisionDecayConfig {
+            initial_confidence: 90.0,
+            decay_factors: vec![DecayFactor {
+                name: "Market".to_string(),
+                decay_rate: 1.0,
+                volatility: 0.5,
+            }],
+            time_horizon_days: 30,
+            start_date: None,
+            half_life_tolerance: 2.0,
+            shock_events: vec![],
+            decay_model: DecayModel::Exponential,
+        };
+        let result = calculate_decision_decay(config);
+
+        let path = decay_sparkline_path(&result, 120.0, 24.0);
+        assert!(path.starts_with("M0.00,"));
+        assert_eq!(path.split(' ').count(), result.confidence_timeline.len());
+        assert!(path.ends_with(&format!("L120.00,{:.2}", 24.0 - result.confidence_timeline[30].confidence / 100.0 * 24.0)));
+
+        let band = decay_band_path(&result, 120.0, 24.0);
+        assert!(band.ends_with(" Z"));
+
+        let empty = DecisionDecayResult {
+            confidence_timeline: vec![],
+            ..result
+        };
+        assert_eq!(decay_sparkline_path(&empty, 120.0, 24.0), "");
+        assert_eq!(decay_band_path(&empty, 120.0, 24.0), "");
+    }
+
+    #[test]
+    fn test_dominance_matrix() {
+        let config = MonteCarloConfig {
+            iterations: 1000,
+            seed: Some(5),
+            ..MonteCarloConfig::default()
+        };
+        let risk = |probability: f64, impact: f64| RiskFactor {
+            name: "Delivery".to_string(),
+            probability,
+            impact_low: impact,
+            impact_high: impact * 2.0,
+            category: RiskCategory::Operational,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        };
+
+        let options = vec![
+            ("Build".to_string(), simulate_scores(88.0, &[risk(0.1, 5.0)], &config)),
+            ("Buy".to_string(), simulate_scores(75.0, &[risk(0.3, 10.0)], &config)),
+            ("Wait".to_string(), simulate_scores(70.0, &[risk(0.5, 15.0)], &config)),
+        ];
+        let m = dominance_matrix(&options);
+
+        assert_eq!(m.len(), 3);
+        assert_eq!(m[0][0], 0.5);
+        assert!(m[0][1] > 0.9 && m[0][2] > 0.9);
+        assert!((m[0][1] + m[1][0] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_required_mitigation() {
+        let risks = vec![
+            RiskFactor {
+                name: "Key Client Churn".to_string(),
+                probability: 0.5,
+                impact_low: 25.0,
+                impact_high: 35.0,
+                category: RiskCategory::Market,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+            RiskFactor {
+                name: "Hiring Delay".to_string(),
+                probability: 0.2,
+                impact_low: 5.0,
+                impact_high: 10.0,
+                category: RiskCategory::Operational,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+        ];
+        let config = MonteCarloConfig {
+            iterations: 2000,
+            seed: Some(9),
+            ..MonteCarloConfig::default()
+        };
+
+        let before = run_monte_carlo_simulation(80.0, &risks, config.clone());
+        assert!(before.risk_of_failure > 0.1);
+
+        let m = required_mitigation(80.0, &risks, 0.1, config.clone()).unwrap();
+        assert_eq!(m.risk_name, "Key Client Churn");
+        assert!(m.reduction > 0.0);
+
+        let mut mitigated = risks.clone();
+        mitigated[0].probability = m.required_probability;
+        let after = run_monte_carlo_simulation(80.0, &mitigated, config.clone());
+        assert!(after.risk_of_failure <= 0.1);
+
+        // From 62 the hiring delay alone fails ~20% of runs; cutting churn can't fix that.
+        assert!(required_mitigation(62.0, &risks, 0.1, config.clone()).is_none());
+
+        // A bigger upside is never the one to mitigate
+        let mut with_upside = risks.clone();
+        with_upside.push(RiskFactor {
+            name: "Partner Deal".to_string(),
+            probability: 0.6,
+            impact_low: 30.0,
+            impact_high: 50.0,
+            category: RiskCategory::Strategic,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Opportunity,
+        });
+        let m = required_mitigation(80.0, &with_upside, 0.05, config.clone()).unwrap();
+        assert_eq!(m.risk_name, "Key Client Churn");
+        assert!(m.resulting_failure_risk <= 0.05);
+        assert!(required_mitigation(80.0, &with_upside[2..], 0.05, config).is_none());
+    }
+
+    #[test]
+    fn test_mean_impact() {
+        let risk = |impact_distribution| RiskFactor {
+            name: "Vendor slips".to_string(),
+            probability: 0.5,
+            impact_low: 10.0,
+            impact_high: 40.0,
+            category: RiskCategory::Operational,
+            impact_distribution,
+            direction: RiskDirection::Threat,
+        };
+        assert_eq!(mean_impact(&risk(ImpactDistribution::Uniform)), 25.0);
+        assert_eq!(mean_impact(&risk(ImpactDistribution::Triangular { mode: 10.0 })), 20.0);
+        // Far from 0 the rectified normal is just the normal
+        assert!((mean_impact(&risk(ImpactDistribution::Normal { mean: 30.0, std: 5.0 })) - 30.0).abs() < 1e-3);
+        // Centred on 0, half the draws are clipped: std / sqrt(2 pi)
+        let clipped = mean_impact(&risk(ImpactDistribution::Normal { mean: 0.0, std: 10.0 }));
+        assert!((clipped - 10.0 / (2.0 * std::f64::consts::PI).sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_compare_to_exemplar() {
+        let exemplar = r#"
+BEST OPTION:
+Migrate billing to Stripe by 2026-03-31 for a $40,000 annual saving.
+
+RATIONALE:
+- Cuts processing fees by 18% based on Q3 2025 volume.
+- Owner: Dana. Review by 2026-01-15.
+
+TOP RISKS:
+- Webhook migration fails; mitigate with a 14 days dual-run.
+
+NEXT ACTIONS:
+1. Deploy sandbox by 2026-01-10 (Owner: Dana)
+"#;
+        let weaker = exemplar
+            .replace(
+                "- Cuts processing fees by 18% based on Q3 2025 volume.\n- Owner: Dana. Review by 2026-01-15.",
+                "- It might possibly save some money, perhaps.",
+            );
+
+        let cfg = ScoringConfig {
+            required_headers: to_strings(&["BEST OPTION", "RATIONALE", "TOP RISKS", "NEXT ACTIONS"]),
+            min_next_actions: 1,
+            ..ScoringConfig::default()
+        };
+        let report = compare_to_exemplar(&weaker, exemplar, &cfg);
+
+        assert_eq!(report.section_deltas.len(), 4);
+        assert_eq!(report.below_exemplar, vec!["RATIONALE".to_string()]);
+        assert_eq!(report.score_delta, 0);
+        assert!(report.composite_delta < 0.0);
+    }
+
+    #[test]
+    fn test_jargon_score() {
+        let heavy = "The SRE team wants the SLO on the CDN tied to our MTTR and the RPO before the QBR.";
+        let plain = "The reliability team wants the uptime target tied to recovery time before the review.";
+        let defined = "We will expose an API (Application Programming Interface) so the API can be reused.";
+
+        assert!(calculate_jargon_score(heavy) < calculate_jargon_score(plain));
+        assert!((calculate_jargon_score(plain) - 1.0).abs() < 1e-9);
+        assert!((calculate_jargon_score(defined) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_failure_curve() {
+        let risks = vec![RiskFactor {
+            name: "Budget Overrun".to_string(),
+            probability: 0.4,
+            impact_low: 10.0,
+            impact_high: 50.0,
+            category: RiskCategory::Financial,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        }];
+        let config = MonteCarloConfig {
+            iterations: 2000,
+            seed: Some(21),
+            failure_thresholds: vec![80.0, 40.0, 60.0, 50.0, 70.0],
+            ..MonteCarloConfig::default()
+        };
+        let result = run_monte_carlo_simulation(85.0, &risks, config);
+
+        let thresholds: Vec<f64> = result.failure_curve.iter().map(|(t, _)| *t).collect();
+        assert_eq!(thresholds, vec![40.0, 50.0, 60.0, 70.0, 80.0]);
+        assert!(result.failure_curve.windows(2).all(|w| w[0].1 <= w[1].1));
+        assert!((result.failure_curve[2].1 - result.risk_of_failure).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unquantified_risks() {
+        let input = r#"
+BEST OPTION:
+Migrate to the managed database.
+
+TOP RISKS:
+- Something could go wrong with the migration
+- Vendor price increase is likely (~30%) at renewal
+- Cutover outage; severe impact on checkout
+
+NEXT ACTIONS:
+1. Schedule cutover
+"#;
+
+        assert_eq!(
+            unquantified_risks(input),
+            vec!["Something could go wrong with the migration".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_section_readability() {
+        let input = r#"
+BEST OPTION:
+Adopt the managed queue. It removes our on-call burden.
+
+RATIONALE:
+- The managed queue costs less than one engineer per year and it removes the on-call rotation that has burned out two teams while also giving us cross-region replication that we would otherwise need to build and maintain ourselves over the next several quarters with no clear owner
+
+TOP RISKS:
+- Vendor lock-in. Mitigate with an adapter layer.
+
+NEXT ACTIONS:
+1. Sign the contract.
+"#;
+
+        let result = score_report_text(input, ScoringConfig::default());
+        let dense = result.section_readability["RATIONALE"];
+        assert!(dense < result.section_readability["BEST OPTION"]);
+        assert!(dense < result.section_readability["TOP RISKS"]);
+        assert!(!result.section_readability.contains_key("HALF-LIFE"));
+    }
+
+    #[test]
+    fn test_load_rubric() {
+        let rubric = r#"{
+            "headers": ["BEST OPTION", "Top Risks", "NEXT ACTIONS"],
+            "min_next_actions": 2,
+            "structural_weight": 0.5,
+            "thresholds": {
+                "proceed_min_score": 90,
+                "caution_min_score": 75,
+                "revise_min_score": 50,
+                "caution_failure_risk": 0.05,
+                "revise_failure_risk": 0.2
+            }
+        }"#;
+        let cfg = load_rubric(rubric).unwrap();
+        assert_eq!(cfg.required_headers, vec!["BEST OPTION", "TOP RISKS", "NEXT ACTIONS"]);
+        assert_eq!(cfg.recommendation_thresholds.proceed_min_score, 90);
+        assert_eq!(cfg.run_on_sentence_words, 40);
+
+        let input = "BEST OPTION:\nShip it.\n\nTOP RISKS:\n- Load spike\n\nNEXT ACTIONS:\n1. Deploy\n2. Monitor\n";
+        let result = score_report_text(input, cfg);
+        assert!(result.missing_headers.is_empty());
+        assert!(result.next_actions_ok);
+        assert_eq!(result.score, 100);
+    }
+
+    #[test]
+    fn test_load_rubric_errors() {
+        assert!(matches!(load_rubric("{ \"headers\": ["), Err(RubricError::Malformed(_))));
+        let missing = r#"{"headers": ["RATIONALE"], "min_next_actions": 3, "structural_weight": 0.7}"#;
+        assert_eq!(
+            load_rubric(missing).unwrap_err(),
+            RubricError::MissingField("thresholds".to_string())
+        );
+
+        let bad_weight = r#"{
+            "headers": ["RATIONALE"],
+            "min_next_actions": 3,
+            "structural_weight": 1.5,
+            "thresholds": {
+                "proceed_min_score": 85, "caution_min_score": 70, "revise_min_score": 40,
+                "caution_failure_risk": 0.1, "revise_failure_risk": 0.3
+            }
+        }"#;
+        assert!(matches!(
+            load_rubric(bad_weight),
+            Err(RubricError::InvalidField { field, .. }) if field == "structural_weight"
+        ));
+
+        // A misspelled field fails instead of falling back to its default
+        let typo = r#"{
+            "headers": ["RATIONALE"],
+            "min_next_actions": 3,
+            "structural_weight": 0.7,
+            "thresholds": {
+                "proceed_min_score": 85, "caution_min_score": 70, "revise_min_score": 40,
+                "caution_failure_risk": 0.1, "revise_failure_risk": 0.3
+            },
+            "penalty_weights": { "missing_headr": 20 }
+        }"#;
+        assert!(matches!(load_rubric(typo), Err(RubricError::Malformed(msg)) if msg.contains("missing_headr")));
+        let top_level = typo.replace("\"penalty_weights\": { \"missing_headr\": 20 }", "\"min_floor\": 50");
+        assert!(matches!(load_rubric(&top_level), Err(RubricError::Malformed(msg)) if msg.contains("min_floor")));
+
+        let stray_weight = typo.replace(
+            "\"penalty_weights\": { \"missing_headr\": 20 }",
+            "\"header_weights\": { \"BEST OPTION\": 20 }",
+        );
+        assert!(matches!(
+            load_rubric(&stray_weight),
+            Err(RubricError::InvalidField { field, .. }) if field == "header_weights"
+        ));
+        let lopsided = typo.replace(
+            "\"penalty_weights\": { \"missing_headr\": 20 }",
+            "\"quality_weights\": { \"clarity\": 0.9 }",
+        );
+        assert!(matches!(
+            load_rubric(&lopsided),
+            Err(RubricError::InvalidField { field, .. }) if field == "quality_weights"
+        ));
+    }
+
+    #[test]
+    fn test_load_rubric_weights() {
+        let rubric = r#"{
+            "headers": ["BEST OPTION", "Top Risks", "NEXT ACTIONS"],
+            "header_weights": { "top risks": 30 },
+            "min_next_actions": 1,
+            "structural_weight": 0.5,
+            "thresholds": {
+                "proceed_min_score": 90, "caution_min_score": 75, "revise_min_score": 50,
+                "caution_failure_risk": 0.05, "revise_failure_risk": 0.2
+            },
+            "penalty_weights": { "missing_header": 5 },
+            "quality_weights": { "clarity": 1.0, "specificity": 0.0, "actionability": 0.0, "completeness": 0.0 }
+        }"#;
+        let cfg = load_rubric(rubric).unwrap();
+        assert_eq!(cfg.header_weights["TOP RISKS"], 30);
+
+        // TOP RISKS costs its own weight, NEXT ACTIONS the default one
+        let result = score_report_text("BEST OPTION:\nShip it.\n", cfg.clone());
+        assert_eq!(result.penalties[0].points, 35);
+
+        let input = "BEST OPTION:\nShip it.\n\nTOP RISKS:\n- Load spike\n\nNEXT ACTIONS:\n1. Deploy\n";
+        let weighted = score_report_text(input, cfg).quality_metrics;
+        let expected = weighted.clarity_score * 0.9 + weighted.lexical_diversity * 0.1;
+        assert!((weighted.overall_quality - expected).abs() < 1e-9);
+        let default = score_report_text(input, ScoringConfig::default()).quality_metrics;
+        assert_ne!(weighted.overall_quality, default.overall_quality);
+    }
+
+    #[test]
+    fn test_scenario_diversity() {
+        let config = || MonteCarloConfig {
+            iterations: 2000,
+            seed: Some(5),
+            ..MonteCarloConfig::default()
+        };
+        let concentrated = run_monte_carlo_simulation(95.0, &[], config());
+        assert!(concentrated.scenario_diversity < 1e-9);
+
+        let risks = vec![RiskFactor {
+            name: "Market shift".to_string(),
+            probability: 0.5,
+            impact_low: 5.0,
+            impact_high: 70.0,
+            category: RiskCategory::Market,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        }];
+        let spread = run_monte_carlo_simulation(95.0, &risks, config());
+        assert!(spread.scenario_diversity > concentrated.scenario_diversity);
+        assert!(spread.scenario_diversity <= 1.0);
+    }
+
+    #[test]
+    fn test_parse_assumptions() {
+        let input = r#"
+BEST OPTION:
+Launch in Germany first.
+
+ASSUMPTIONS TO VALIDATE:
+1. [x] Payment provider supports SEPA
+2. [ ] Support team can cover CET hours
+3. Demand matches the UK pilot (validated)
+4. Pricing in EUR needs no legal review (disproven)
+5. Localization takes under two weeks
+
+NEXT ACTIONS:
+1. Sign provider contract
+"#;
+
+        let parsed = parse_assumptions(input);
+        let statuses: Vec<ValidationStatus> = parsed.iter().map(|a| a.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ValidationStatus::Validated,
+                ValidationStatus::Pending,
+                ValidationStatus::Validated,
+                ValidationStatus::Invalidated,
+                ValidationStatus::Pending,
+            ]
+        );
+        assert_eq!(parsed[0].text, "Payment provider supports SEPA");
+        assert_eq!(parsed[2].text, "Demand matches the UK pilot");
+    }
+
+    #[test]
+    fn test_portfolio_score() {
+        let cfg = ScoringConfig::default;
+        let strong = score_report_text(&minimal_template(&cfg()), cfg());
+        let weak = score_report_text("BEST OPTION:\nTBD\n", cfg());
+        assert!(weak.must_repair && !strong.must_repair);
+
+        let heavy_weak = portfolio_score(&[(9.0, weak.clone()), (1.0, strong.clone())]);
+        let light_weak = portfolio_score(&[(1.0, weak.clone()), (9.0, strong.clone())]);
+
+        assert!(heavy_weak.weighted_score < light_weak.weighted_score);
+        assert!((heavy_weak.must_repair_share - 0.9).abs() < 1e-9);
+        assert_eq!(heavy_weak.worst_index, Some(0));
+        assert_eq!(heavy_weak.worst_score, Some(weak.score));
+    }
+
+    #[test]
+    fn test_decisiveness_score() {
+        let rest = "\n\nRATIONALE:\n- Lowest cost\n\nNEXT ACTIONS:\n1. Sign contract\n";
+        let firm = format!("BEST OPTION:\nWe recommend Option A: migrate to the managed database in Q3.{}", rest);
+        let hedged = format!(
+            "BEST OPTION:\nMaybe we could possibly consider Option A, perhaps in Q3 depending on budget.{}",
+            rest
+        );
+
+        let firm = score_report_text(&firm, ScoringConfig::default()).quality_metrics;
+        let hedged = score_report_text(&hedged, ScoringConfig::default()).quality_metrics;
+        assert!(firm.decisiveness_score > 0.9);
+        assert!(hedged.decisiveness_score < firm.decisiveness_score);
+        assert!(hedged.decisiveness_score < 0.5);
+    }
+
+    #[test]
+    fn test_add_risk_delta() {
+        let existing = vec![RiskFactor {
+            name: "Minor delay".to_string(),
+            probability: 0.3,
+            impact_low: 2.0,
+            impact_high: 8.0,
+            category: RiskCategory::Operational,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        }];
+        let new_risk = RiskFactor {
+            name: "Key customer churns".to_string(),
+            probability: 0.4,
+            impact_low: 25.0,
+            impact_high: 45.0,
+            category: RiskCategory::Market,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        };
+        let config = MonteCarloConfig {
+            iterations: 4000,
+            seed: Some(99),
+            ..MonteCarloConfig::default()
+        };
+
+        let delta = add_risk_delta(85.0, &existing, new_risk.clone(), config.clone());
+        assert_eq!(delta.risk_name, "Key customer churns");
+        assert!(delta.risk_of_failure_delta > 0.2, "delta = {}", delta.risk_of_failure_delta);
+        assert!(delta.mean_score_delta < 0.0);
+        assert!(delta.cvar_delta > 0.0);
+
+        // Same figures as the full simulation of each risk set
+        let before = run_monte_carlo_simulation(85.0, &existing, config.clone());
+        let after = run_monte_carlo_simulation(85.0, &[existing[0].clone(), new_risk], config);
+        assert!((delta.cvar_before - before.conditional_var).abs() < 1e-9);
+        assert!((delta.cvar_after - after.conditional_var).abs() < 1e-9);
+        assert!((delta.mean_score_delta - (after.mean_score - before.mean_score)).abs() < 1e-9);
+        assert!((delta.risk_of_failure_delta - (after.risk_of_failure - before.risk_of_failure)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_validate_half_life_claim() {
+        // Decay rate 3.3%/day puts the modeled half-life at ~21 days.
+        let decay = DecisionDecayConfig {
+            initial_confidence: 80.0,
+            decay_factors: vec![DecayFactor {
+                name: "Competitor launches".to_string(),
+                decay_rate: 3.3,
+                volatility: 0.2,
+            }],
+            time_horizon_days: 365,
+            start_date: None,
+            half_life_tolerance: 2.0,
+            shock_events: vec![],
+            decay_model: DecayModel::Exponential,
+        };
+
+        let claim = |stated: &str| format!("BEST OPTION:\nShip v2.\n\nHALF-LIFE:\n{}\n\nNEXT ACTIONS:\n1. Ship\n", stated);
+
+        let mismatch = validate_half_life_claim(&claim("About 180 days before the market shifts."), &decay).unwrap();
+        assert_eq!(mismatch.stated_days, 180.0);
+        assert!((mismatch.computed_days - 21.0).abs() <= 1.0);
+        assert!(mismatch.ratio > 8.0);
+
+        assert!(validate_half_life_claim(&claim("Roughly three weeks."), &decay).is_none());
+        assert!(validate_half_life_claim(&claim("Hard to say."), &decay).is_none());
+    }
+
+    #[test]
+    fn test_score_contributions() {
+        let cfg = ScoringConfig::default();
+        let inputs = [
+            minimal_template(&cfg),
+            "BEST OPTION:\nOption A\n\nBEST OPTION:\nOption B\n\nRATIONALE:\n- cheap\n".to_string(),
+            String::new(),
+        ];
+
+        for input in &inputs {
+            let result = score_report_text(input, cfg.clone());
+            let contributions = score_contributions(&result, &cfg);
+            let sum: i32 = contributions.iter().map(|c| c.points).sum();
+            assert_eq!(sum, result.score as i32);
+        }
+
+        // An empty report's penalties overshoot zero and need reconciling
+        let result = score_report_text("", cfg.clone());
+        let last = score_contributions(&result, &cfg).pop().unwrap();
+        assert_eq!(last.label, "Clamp adjustment");
+        assert!(last.points > 0);
+    }
+
+    #[test]
+    fn test_exit_plan() {
+        let cfg = ScoringConfig::default();
+        let without = minimal_template(&cfg);
+        let with = without.replace(
+            "- [FILL IN: blind spots]",
+            "- Vendor lock-in; rollback plan: kill switch reverts to the old checkout within 1 hour",
+        );
+
+        let plain = score_report_text(&without, cfg.clone());
+        assert!(!plain.has_exit_plan);
+        assert_eq!(plain.exit_plan_score, 0.0);
+
+        let planned = score_report_text(&with, cfg.clone());
+        assert!(planned.has_exit_plan);
+        assert!(planned.exit_plan_score > plain.exit_plan_score);
+        assert_eq!(planned.exit_plan_bonus, 0);
+
+        let cfg = ScoringConfig { exit_plan_bonus: 5, min_next_actions: 8, ..ScoringConfig::default() };
+        let plain = score_report_text(&without, cfg.clone());
+        let planned = score_report_text(&with, cfg.clone());
+        assert_eq!(planned.exit_plan_bonus, 5);
+        assert_eq!(planned.score, plain.score + 5);
+
+        let sum: i32 = score_contributions(&planned, &cfg).iter().map(|c| c.points).sum();
+        assert_eq!(sum, planned.score as i32);
+        assert_eq!(score_waterfall(&planned).last().unwrap().running_total, planned.score as i32);
+    }
+
+    #[test]
+    fn test_bundle() {
+        let score = score_report_text(&minimal_template(&ScoringConfig::default()), ScoringConfig::default());
+        let decay = calculate_decision_decay(DecisionDecayConfig {
+            initial_confidence: 80.0,
+            decay_factors: vec![DecayFactor {
+                name: "Market".to_string(),
+                decay_rate: 1.0,
+                volatility: 0.1,
+            }],
+            time_horizon_days: 90,
+            start_date: None,
+            half_life_tolerance: 1.5,
+            shock_events: vec![],
+            decay_model: DecayModel::Exponential,
+        });
+
+        let json = bundle(&score, None, None, Some(&decay));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value.get("monte_carlo").is_none());
+        assert!(value.get("sensitivity").is_none());
+        assert!(value.get("decay").is_some());
+
+        let parsed: AnalysisBundle = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.schema_version, BUNDLE_SCHEMA_VERSION);
+        assert!(parsed.generated_at.is_some());
+        assert_eq!(parsed.score.score, score.score);
+        assert_eq!(parsed.decay.unwrap().half_life_days, decay.half_life_days);
+        assert!(parsed.monte_carlo.is_none() && parsed.sensitivity.is_none());
+    }
+
+    #[test]
+    fn test_detect_circular_rationale() {
+        let circular = "BEST OPTION:\nMigrate to Postgres\n\nRATIONALE:\n- Migrating to Postgres is the best option\n- Postgres is the right choice\n";
+        assert!(detect_circular_rationale(circular));
+
+        let substantive = "BEST OPTION:\nMigrate to Postgres\n\nRATIONALE:\n- Cuts licence cost by $40k a year\n- Benchmark shows 2x faster writes\n";
+        assert!(!detect_circular_rationale(substantive));
+
+        assert!(!detect_circular_rationale("BEST OPTION:\nMigrate to Postgres\n"));
+    }
+
+    #[test]
+    fn test_grade_distribution() {
+        let cfg = ScoringConfig::default();
+        assert!(grade_distribution(&[], &cfg).is_empty());
+
+        let full = minimal_template(&cfg);
+        let corpus: Vec<ScoreResult> = [full.as_str(), full.as_str(), "BEST OPTION:\nA\n", ""]
+            .iter()
+            .map(|t| score_report_text(t, cfg.clone()))
+            .collect();
+
+        let distribution = grade_distribution(&corpus, &cfg);
+        let total: f64 = distribution.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!((distribution[&Grade::A] - 0.5).abs() < 1e-9);
+        assert!((distribution[&Grade::D] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_penalty_weights() {
+        // Empty RATIONALE, duplicate BEST OPTION, four of six actions
+        let input = minimal_template(&ScoringConfig::default())
+            .replace("- [FILL IN: rationale]", "-")
+            .replace("RATIONALE:", "BEST OPTION:\nOption B\n\nRATIONALE:")
+            .replace("5. [FILL IN: next action 5 - owner, deadline]\n", "")
+            .replace("6. [FILL IN: next action 6 - owner, deadline]", "");
+
+        let default = score_report_text(&input, ScoringConfig::default());
+        assert_eq!(default.score, 100 - 8 - 6 - (10 + 2 * 3));
+
+        let cfg = ScoringConfig {
+            penalty_weights: PenaltyWeights {
+                empty_section: 20,
+                duplicate_header: 2,
+                next_actions_base: 5,
+                next_actions_per_deficit: 1,
+                ..PenaltyWeights::default()
+            },
+            ..ScoringConfig::default()
+        };
+        let custom = score_report_text(&input, cfg.clone());
+        assert_eq!(custom.score, 100 - 20 - 2 - (5 + 2));
+        assert_eq!(next_action_marginal_value(4, &cfg), 1);
+    }
+
+    #[test]
+    fn test_next_actions_table() {
+        let input = minimal_template(&ScoringConfig::default())
+            .split("NEXT ACTIONS:")
+            .next()
+            .unwrap()
+            .to_string()
+            + "NEXT ACTIONS:\n| Action | Owner | Due |\n|---|---|---|\n| Sign vendor contract | Dana | 2026-11-01 |\n| Migrate staging | Lee | 2026-11-15 |\n| Announce cutover | Dana | |\n";
+
+        let cfg = ScoringConfig { min_next_actions: 3, ..ScoringConfig::default() };
+        let result = score_report_text(&input, cfg.clone());
+        assert_eq!(result.next_actions_count, 3);
+        assert!(result.next_actions_ok);
+
+        let rows = next_actions_table(&input);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].action, "Sign vendor contract");
+        assert_eq!(rows[1].due.as_deref(), Some("2026-11-15"));
+        assert_eq!(rows[2].due, None);
+
+        let oc = owner_concentration(&input, &cfg);
+        assert_eq!(oc.total_actions, 3);
+        assert_eq!(oc.owners, vec![("Dana".to_string(), 2), ("Lee".to_string(), 1)]);
+        assert_eq!(oc.unowned_actions, 0);
+    }
+
+    #[test]
+    fn test_runtime_required_headers() {
+        let headers: Vec<String> = "BEST OPTION,Decision Owner,NEXT ACTIONS"
+            .split(',')
+            .map(str::to_string)
+            .collect();
+        let cfg = ScoringConfig { required_headers: headers, min_next_actions: 2, ..ScoringConfig::default() };
+
+        let input = "BEST OPTION:\nShip it.\n\nNEXT ACTIONS:\n1. Deploy\n2. Monitor\n\nDECISION OWNER:\n- Dana\n";
+        let result = score_report_text(input, cfg.clone());
+        assert!(result.missing_headers.is_empty());
+        assert_eq!(result.next_actions_count, 2);
+
+        let result = score_report_text("BEST OPTION:\nShip it.\n", cfg);
+        assert_eq!(result.missing_headers, vec!["Decision Owner", "NEXT ACTIONS"]);
+    }
+
+    #[test]
+    fn test_tornado_contribution_pct() {
+        let variable = |name: &str, weight: f64| SensitivityVariable {
+            name: name.to_string(),
+            base_value: 100.0,
+            min_value: 80.0,
+            max_value: 120.0,
+            weight,
+        };
+        let config = SensitivityConfig {
+            variables: vec![variable("Budget", 1.0), variable("Timeline", 3.0), variable("Headcount", 0.5)],
+            step_count: 10,
+        };
+        let result = run_sensitivity_analysis(60.0, config);
+
+        let total: f64 = result.tornado_chart_data.iter().map(|b| b.contribution_pct).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        let top = &result.tornado_chart_data[0];
+        assert_eq!(top.variable_name, "Timeline");
+        assert!(result.tornado_chart_data.iter().all(|b| b.contribution_pct <= top.contribution_pct));
+    }
+
+    #[test]
+    fn test_fuzzy_header_matching() {
+        let input = minimal_template(&ScoringConfig::default())
+            .replace("BEST OPTION:", "BEST OPTIONS:")
+            .replace("TOP RISKS:", "TOP RISK:")
+            .replace("RATIONALE:", "RATINALE:");
+
+        let exact = score_report_text(&input, ScoringConfig::default());
+        assert_eq!(exact.missing_headers, vec!["BEST OPTION", "RATIONALE", "TOP RISKS"]);
+
+        let cfg = ScoringConfig { header_match_mode: HeaderMatchMode::Fuzzy(1), ..ScoringConfig::default() };
+        let fuzzy = score_report_text(&input, cfg);
+        assert!(fuzzy.missing_headers.is_empty());
+        assert_eq!(fuzzy.score, 100);
+        assert!(fuzzy.notes.contains(&"Matched 'TOP RISK' to 'TOP RISKS' (distance 1)".to_string()));
+        assert!(fuzzy.notes.contains(&"Matched 'BEST OPTIONS' to 'BEST OPTION' (distance 1)".to_string()));
+        assert!(fuzzy.notes.contains(&"Matched 'RATINALE' to 'RATIONALE' (distance 1)".to_string()));
+
+        // A one-character typo stays missing when the threshold is zero
+        let cfg = ScoringConfig { header_match_mode: HeaderMatchMode::Fuzzy(0), ..ScoringConfig::default() };
+        assert_eq!(score_report_text(&input, cfg).missing_headers.len(), 3);
+    }
+
+    #[test]
+    fn test_truncation_cost() {
+        let cfg = ScoringConfig::default();
+        let template = minimal_template(&cfg);
+        assert_eq!(truncation_cost(&score_report_text(&template, cfg.clone()), &cfg), 0);
+
+        let truncated = format!("{}\n7. Follow up with...", template);
+        let cfg = ScoringConfig {
+            penalty_weights: PenaltyWeights { truncation: 15, ..PenaltyWeights::default() },
+            ..ScoringConfig::default()
+        };
+        let result = score_report_text(&truncated, cfg.clone());
+        assert!(result.truncation_suspected);
+        assert_eq!(truncation_cost(&result, &cfg), 15);
+        assert_eq!(result.score, 85);
+    }
+
+    #[test]
+    fn test_detect_placeholders() {
+        assert_eq!(detect_placeholders("TBD"), vec!["TBD"]);
+        assert_eq!(detect_placeholders("- [reasoning]\n- see <link here>"), vec!["[reasoning]", "<link here>"]);
+        assert!(detect_placeholders("[x] Budget approved\n[ ] Vendor signed [1], see [docs](https://x.io)").is_empty());
+        assert!(detect_placeholders("Costs fall by 20% within <5 weeks").is_empty());
+
+        let input = minimal_template(&ScoringConfig::default())
+            .replace("- [FILL IN: best option]", "Adopt the regional cluster")
+            .replace("- [FILL IN: rationale]", "TBD");
+        let cfg = ScoringConfig {
+            penalty_weights: PenaltyWeights { placeholder_section: 4, ..PenaltyWeights::default() },
+            ..ScoringConfig::default()
+        };
+        let result = score_report_text(&input, cfg);
+        assert_eq!(result.placeholder_sections, vec!["RATIONALE".to_string()]);
+        assert!(result.empty_sections.is_empty());
+        assert_eq!(result.score, 100 - 4);
+
+        // Penalized by default; the template's own prompts are not
+        let result = score_report_text(&input, ScoringConfig::default());
+        assert_eq!(result.score, 100 - PenaltyWeights::default().placeholder_section);
+        assert!(detect_placeholders("- [FILL IN: rationale]").is_empty());
+    }
+
+    #[test]
+    fn test_regime_priors() {
+        let risks = vec![
+            RiskFactor {
+                name: "Demand drop".to_string(),
+                probability: 0.2,
+                impact_low: 15.0,
+                impact_high: 30.0,
+                category: RiskCategory::Market,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+            RiskFactor {
+                name: "Credit tightens".to_string(),
+                probability: 0.15,
+                impact_low: 10.0,
+                impact_high: 20.0,
+                category: RiskCategory::Financial,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+        ];
+        let config = |priors: Vec<(f64, f64)>| MonteCarloConfig {
+            iterations: 5000,
+            seed: Some(7),
+            regime_priors: priors,
+            ..MonteCarloConfig::default()
+        };
+
+        let neutral = run_monte_carlo_simulation(80.0, &risks, config(vec![(1.0, 1.0)]));
+        let recession = run_monte_carlo_simulation(80.0, &risks, config(vec![(0.8, 2.5), (0.2, 1.0)]));
+        assert!(
+            recession.risk_of_failure > neutral.risk_of_failure + 0.1,
+            "neutral = {}, recession = {}",
+            neutral.risk_of_failure,
+            recession.risk_of_failure
+        );
+    }
+
+    #[test]
+    fn test_flag_point_estimates() {
+        let input = "RATIONALE:\n- This will increase revenue by exactly $2.3M next year. Churn falls 4.5% too.\n- Savings of $2-3M over two years\n- Conversion should rise ~12%\n- Margin improves 10 to 15%\n- Ship on 2026-11-01\n";
+        assert_eq!(
+            flag_point_estimates(input),
+            vec![
+                "This will increase revenue by exactly $2.3M next year",
+                "Churn falls 4.5% too",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_correlated_risks() {
+        let risk = |name: &str| RiskFactor {
+            name: name.to_string(),
+            probability: 0.3,
+            impact_low: 20.0,
+            impact_high: 20.0,
+            category: RiskCategory::Market,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        };
+        let risks = vec![risk("Demand drop"), risk("Price war")];
+        let both_hit = |matrix: Option<Vec<Vec<f64>>>| {
+            let config = MonteCarloConfig {
+                iterations: 5000,
+                seed: Some(11),
+                correlation_matrix: matrix,
+                ..MonteCarloConfig::default()
+            };
+            let scores = simulate_scores(80.0, &risks, &config);
+            scores.iter().filter(|&&s| s <= 40.0).count() as f64 / scores.len() as f64
+        };
+
+        let independent = both_hit(Some(vec![vec![1.0, 0.0], vec![0.0, 1.0]]));
+        let correlated = both_hit(Some(vec![vec![1.0, 1.0], vec![1.0, 1.0]]));
+        assert!((independent - 0.09).abs() < 0.03, "independent = {}", independent);
+        assert!((correlated - 0.3).abs() < 0.03, "correlated = {}", correlated);
+
+        // A malformed matrix falls back to independent sampling
+        assert_eq!(both_hit(Some(vec![vec![1.0]])), both_hit(None));
+    }
+
+    #[test]
+    fn test_durability_adjusted_score() {
+        let decay_at = |rate: f64| {
+            calculate_decision_decay(DecisionDecayConfig {
+                initial_confidence: 90.0,
+                decay_factors: vec![DecayFactor {
+                    name: "Market".to_string(),
+                    decay_rate: rate,
+                    volatility: 0.1,
+                }],
+                time_horizon_days: 365,
+                start_date: None,
+                half_life_tolerance: 2.0,
+                shock_events: vec![],
+                decay_model: DecayModel::Exponential,
+            })
+        };
+        let stable = decay_at(0.1);
+        let critical = decay_at(10.0);
+        assert_eq!(stable.decay_classification, DecayClassification::Stable);
+        assert_eq!(critical.decay_classification, DecayClassification::Critical);
+
+        assert!(durability_adjusted_score(90, &critical) < durability_adjusted_score(90, &stable));
+        assert!((durability_adjusted_score(90, &critical) - 45.0).abs() < 1e-9);
+
+        let lenient = DurabilityDiscounts { critical: 0.8, ..DurabilityDiscounts::default() };
+        assert!((durability_adjusted_score_with(90, &critical, &lenient) - 72.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_triangular_impact() {
+        let impacts = |distribution: ImpactDistribution| -> Vec<f64> {
+            let risk = RiskFactor {
+                name: "Outage".to_string(),
+                probability: 1.0,
+                impact_low: 0.0,
+                impact_high: 100.0,
+                category: RiskCategory::Technical,
+                impact_distribution: distribution,
+                direction: RiskDirection::Threat,
+            };
+            let config = MonteCarloConfig { iterations: 5000, seed: Some(3), ..MonteCarloConfig::default() };
+            simulate_scores(100.0, &[risk], &config).iter().map(|s| 100.0 - s).collect()
+        };
+        let near_mode = |xs: &[f64]| xs.iter().filter(|&&x| (5.0..=15.0).contains(&x)).count() as f64 / xs.len() as f64;
+        let mean = |xs: &[f64]| xs.iter().sum::<f64>() / xs.len() as f64;
+
+        let uniform = impacts(ImpactDistribution::Uniform);
+        let triangular = impacts(ImpactDistribution::Triangular { mode: 10.0 });
+        assert!((mean(&uniform) - 50.0).abs() < 2.0);
+        assert!((mean(&triangular) - 110.0 / 3.0).abs() < 2.0);
+        assert!(near_mode(&triangular) > near_mode(&uniform) * 1.5);
+
+        let normal = impacts(ImpactDistribution::Normal { mean: 20.0, std: 5.0 });
+        assert!((mean(&normal) - 20.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_next_actions_stop_at_custom_header() {
+        let input = format!(
+            "{}\n\nAPPROVALS:\n- Finance sign-off\n- Legal review\n- Security review\n",
+            minimal_template(&ScoringConfig::default())
+        );
+        let result = score_report_text(&input, ScoringConfig::default());
+        assert_eq!(result.next_actions_count, 6);
+
+        // Required headers still end the list without a colon
+        let cfg = ScoringConfig {
+            required_headers: to_strings(&["NEXT ACTIONS", "SIGN OFF"]),
+            min_next_actions: 2,
+            ..ScoringConfig::default()
+        };
+        let input = "NEXT ACTIONS\n1. Deploy\n2. Monitor\n\nSIGN OFF\n1. Dana\n2. Lee\n3. Kim\n";
+        assert_eq!(score_report_text(input, cfg).next_actions_count, 2);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let risks = vec![
+            RiskFactor {
+                name: "Vendor slips".to_string(),
+                probability: 0.3,
+                impact_low: 5.0,
+                impact_high: 20.0,
+                category: RiskCategory::Operational,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+            RiskFactor {
+                name: "Demand drop".to_string(),
+                probability: 0.2,
+                impact_low: 10.0,
+                impact_high: 30.0,
+                category: RiskCategory::Market,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+        ];
+        let config = |parallel: bool| MonteCarloConfig {
+            iterations: 20000,
+            seed: Some(21),
+            parallel,
+            ..MonteCarloConfig::default()
+        };
+
+        let serial = run_monte_carlo_simulation(80.0, &risks, config(false));
+        let parallel = run_monte_carlo_simulation(80.0, &risks, config(true));
+        assert_eq!(parallel.iterations_run, serial.iterations_run);
+        assert!((parallel.mean_score - serial.mean_score).abs() < 0.5);
+        assert!((parallel.std_dev - serial.std_dev).abs() < 0.5);
 
-fn clean_model_text(s: &str) -> String {
-    let mut out = s.replace("\r\n", "\n");
+        // Same seed, same chunks: the parallel path is reproducible
+        let again = run_monte_carlo_simulation(80.0, &risks, config(true));
+        assert_eq!(again.mean_score, parallel.mean_score);
+    }
 
-    out = out.replace("```", "");
+    #[test]
+    fn test_email_digest() {
+        let cfg = ScoringConfig::default();
+        let input = minimal_template(&cfg)
+            .replace("BLIND SPOTS:", "")
+            .replace("6. [FILL IN: next action 6 - owner, deadline]", "");
+        let result = score_report_text(&input, cfg.clone());
+        assert_eq!(result.score, 75);
+
+        let digest = email_digest(&result, &cfg);
+        let mut lines = digest.lines();
+        assert_eq!(lines.next(), Some("Subject: Decision report scored 75/100 (B) — needs revision"));
+        assert!(digest.contains("Top issue: NEXT ACTIONS count too low (-13 points)"));
+        assert!(digest.contains("Next actions: 5 of 6 required (1 short)"));
+        assert!(!digest.contains('#') && !digest.contains("**"));
+
+        let clean = score_report_text(&minimal_template(&cfg), cfg.clone());
+        assert!(email_digest(&clean, &cfg).starts_with("Subject: Decision report scored 100/100 (A) — ready to proceed"));
+    }
 
-    let re_md_head = Regex::new(r"(?m)^\s{0,3}#{1,6}\s+").unwrap();
-    out = re_md_head.replace_all(&out, "").to_string();
+    #[test]
+    fn test_value_at_risk() {
+        let risks = vec![
+            RiskFactor {
+                name: "Vendor slips".to_string(),
+                probability: 0.3,
+                impact_low: 5.0,
+                impact_high: 20.0,
+                category: RiskCategory::Operational,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+            RiskFactor {
+                name: "Demand drop".to_string(),
+                probability: 0.1,
+                impact_low: 20.0,
+                impact_high: 40.0,
+                category: RiskCategory::Market,
+                impact_distribution: ImpactDistribution::Uniform,
+                direction: RiskDirection::Threat,
+            },
+        ];
+        let config = MonteCarloConfig { iterations: 5000, seed: Some(5), ..MonteCarloConfig::default() };
+        let result = run_monte_carlo_simulation(85.0, &risks, config);
 
-    let re_sep = Regex::new(r"(?m)^\s*[-=_]{3,}\s*$").unwrap();
-    out = re_sep.replace_all(&out, "").to_string();
+        assert!((result.value_at_risk - (85.0 - result.percentile_5)).abs() < 1e-9);
+        assert!(result.value_at_risk > 0.0);
+        assert!(result.conditional_var >= result.value_at_risk);
+    }
 
-    out = out
-        .lines()
-        .map(|l| l.trim_end().to_string())
-        .collect::<Vec<_>>()
-        .join("\n");
+    #[test]
+    fn test_histogram_bins() {
+        let risks = vec![RiskFactor {
+            name: "Vendor slips".to_string(),
+            probability: 0.4,
+            impact_low: 5.0,
+            impact_high: 25.0,
+            category: RiskCategory::Operational,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Threat,
+        }];
+        let config = MonteCarloConfig { iterations: 3000, seed: Some(8), ..MonteCarloConfig::default() };
+        let result = run_monte_carlo_simulation(80.0, &risks, config);
+
+        assert_eq!(result.histogram.len(), 20);
+        assert_eq!(result.histogram.iter().map(|b| b.count).sum::<usize>(), result.iterations_run);
+        assert_eq!(result.histogram[0].range_start, result.min_score);
+        assert_eq!(result.histogram[19].range_end, result.max_score);
+
+        assert_eq!(histogram_bins(&[50.0, 50.0], 10), vec![HistogramBin { range_start: 50.0, range_end: 50.0, count: 2 }]);
+    }
 
-    out.trim().to_string()
-}
+    #[test]
+    fn test_opportunity_raises_score() {
+        let risks = vec![RiskFactor {
+            name: "Partner co-markets the launch".to_string(),
+            probability: 0.5,
+            impact_low: 5.0,
+            impact_high: 10.0,
+            category: RiskCategory::Market,
+            impact_distribution: ImpactDistribution::Uniform,
+            direction: RiskDirection::Opportunity,
+        }];
+        let config = MonteCarloConfig { iterations: 2000, seed: Some(4), ..MonteCarloConfig::default() };
+        let result = run_monte_carlo_simulation(70.0, &risks, config);
+
+        assert!(result.mean_score > 70.0, "mean = {}", result.mean_score);
+        assert!(result.max_score <= 80.0 && result.min_score >= 70.0);
+    }
 
-fn normalize_for_headers(s: &str) -> String {
-    let mut out = s.to_string();
+    #[test]
+    fn test_prng_low_bits_uniform() {
+        // Chi-square over consecutive pairs of the two low bits (16 cells)
+        let chi_square = |mut next: Box<dyn FnMut() -> u64>| {
+            let samples = 40_000;
+            let mut counts = [0usize; 16];
+            let mut prev = next() & 3;
+            for _ in 0..samples {
+                let cur = next() & 3;
+                counts[(prev * 4 + cur) as usize] += 1;
+                prev = cur;
+            }
+            let expected = samples as f64 / 16.0;
+            counts.iter().map(|&c| (c as f64 - expected).powi(2) / expected).sum::<f64>()
+        };
+        // 15 degrees of freedom, p = 0.001
+        let critical = 37.7;
 
-    out = out.replace("•", "- ");
-    out = out.replace("–", "- ");
-    out = out.replace("—", "- ");
+        let mut rng = Xoshiro256PlusPlus::new(12345);
+        assert!(chi_square(Box::new(move || rng.next_u64())) < critical);
 
-    let re_colon = Regex::new(r"(?m)^\s*([A-Z][A-Z0-9 \-]{2,})\s*:\s*$").unwrap();
-    out = re_colon.replace_all(&out, "$1:").to_string();
+        let mut state: u64 = 12345;
+        let lcg = move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            state
+        };
+        assert!(chi_square(Box::new(lcg)) > critical);
+    }
 
-    out.to_uppercase()
-}
+    #[test]
+    fn test_two_way_sensitivity() {
+        let budget = SensitivityVariable {
+            name: "Budget".to_string(),
+            base_value: 100.0,
+            min_value: 50.0,
+            max_value: 150.0,
+            weight: 1.0,
+        };
+        let timeline = SensitivityVariable {
+            name: "Timeline".to_string(),
+            base_value: 12.0,
+            min_value: 6.0,
+            max_value: 18.0,
+            weight: -2.0,
+        };
+        let result = run_two_way_sensitivity(70.0, &budget, &timeline, 4);
+
+        assert_eq!(result.a_values.len(), 5);
+        assert_eq!(result.b_values, vec![6.0, 9.0, 12.0, 15.0, 18.0]);
+        assert_eq!(result.scores.len(), 5);
+        assert!(result.scores.iter().all(|row| row.len() == 5));
+        // Base values of both variables sit at the grid centre
+        assert!((result.scores[2][2] - 70.0).abs() < 1e-9);
+        assert!(result.scores[4][0] > result.scores[0][4]);
+    }
 
-fn evaluate_headers(
-    normalized_upper: &str,
-    required: &[&str],
-) -> (Vec<String>, Vec<String>, Vec<String>) {
-    let mut missing: Vec<String> = Vec::new();
-    let mut dupes: Vec<String> = Vec::new();
-    let mut empty: Vec<String> = Vec::new();
+    #[test]
+    fn test_sensitivity_analysis_with() {
+        let config = || SensitivityConfig {
+            variables: vec![SensitivityVariable {
+                name: "Team size".to_string(),
+                base_value: 10.0,
+                min_value: 5.0,
+                max_value: 40.0,
+                weight: 1.0,
+            }],
+            step_count: 7,
+        };
 
-    let bullet_re = Regex::new(r"(?m)^\s*[-*]\s+\S+").unwrap();
-    let num_re = Regex::new(r"(?m)^\s*\d{1,2}[\.\)]\s+\S+").unwrap();
-    let word_re = Regex::new(r"[A-Z0-9]{2,}").unwrap();
+        let linear = run_sensitivity_analysis(60.0, config());
+        // Diminishing returns: each doubling adds the same number of points
+        let log = run_sensitivity_analysis_with(60.0, config(), |var, value| {
+            60.0 + 10.0 * var.weight * (value / var.base_value).ln()
+        });
 
-    for &h in required {
-        let header_re = Regex::new(&format!(r"(?m)^\s*{}\s*:?\s*$", regex::escape(h))).unwrap();
-        let matches: Vec<_> = header_re.find_iter(normalized_upper).collect();
+        let (l, g) = (&linear.variable_impacts[0], &log.variable_impacts[0]);
+        assert!((g.score_at_max - (60.0 + 10.0 * 4f64.ln())).abs() < 1e-9);
+        assert!((g.elasticity - l.elasticity).abs() > 0.05);
+        assert!(g.score_at_max < l.score_at_max);
+    }
 
-        if matches.is_empty() {
-            missing.push(h.to_string());
-            continue;
-        }
-        if matches.len() > 1 {
-            dupes.push(h.to_string());
-        }
+    #[test]
+    fn test_spider_data() {
+        let variable = |name: &str| SensitivityVariable {
+            name: name.to_string(),
+            base_value: 100.0,
+            min_value: 50.0,
+            max_value: 150.0,
+            weight: 1.0,
+        };
+        let config = SensitivityConfig { variables: vec![variable("Budget"), variable("Scope")], step_count: 4 };
+        let result = run_sensitivity_analysis(70.0, config);
+
+        assert_eq!(result.spider_data.len(), 2);
+        assert!(result.spider_data.iter().all(|line| line.points.len() == 5));
+        let budget = &result.spider_data[0];
+        assert_eq!(budget.points.first().unwrap().0, -50.0);
+        assert_eq!(budget.points[2], (0.0, 70.0));
+    }
 
-        let first = matches[0].end();
-        let after = &normalized_upper[first..];
+    #[test]
+    fn test_break_even_value() {
+        let variable = |name: &str, weight: f64| SensitivityVariable {
+            name: name.to_string(),
+            base_value: 100.0,
+            min_value: 0.0,
+            max_value: 200.0,
+            weight,
+        };
+        let config = SensitivityConfig {
+            variables: vec![variable("Budget", 1.0), variable("Delay", -1.0), variable("Office plants", 0.1)],
+            step_count: 10,
+        };
+        let result = run_sensitivity_analysis(70.0, config);
+        let impact = |name: &str| result.variable_impacts.iter().find(|v| v.variable_name == name).unwrap();
+
+        // 70 + (v - 100) / 100 * 20 = 60 at v = 50
+        assert!((impact("Budget").break_even_value.unwrap() - 50.0).abs() < 1e-9);
+        // Negative weight: the unfavorable end is the top of the range
+        assert!((impact("Delay").break_even_value.unwrap() - 150.0).abs() < 1e-9);
+        assert_eq!(impact("Office plants").break_even_value, None);
+    }
 
-        let next_header_re = Regex::new(&format!(
-            r"(?m)^\s*({})\s*:?\s*$",
-            required
-                .iter()
-                .map(|x| regex::escape(x))
-                .collect::<Vec<_>>()
-                .join("|")
-        ))
-        .unwrap();
+    #[test]
+    fn test_shock_events() {
+        let config = |shock_events: Vec<ShockEvent>| DecisionDecayConfig {
+            initial_confidence: 80.0,
+            decay_factors: vec![DecayFactor {
+                name: "Market".to_string(),
+                decay_rate: 1.0,
+                volatility: 0.1,
+            }],
+            time_horizon_days: 365,
+            start_date: None,
+            half_life_tolerance: 2.0,
+            shock_events,
+            decay_model: DecayModel::Exponential,
+        };
 
-        let end_idx = next_header_re
-            .find(after)
-            .map(|m| m.start())
-            .unwrap_or(after.len());
+        let smooth = calculate_decision_decay(config(vec![]));
+        let shocked = calculate_decision_decay(config(vec![ShockEvent { day: 30, confidence_drop: 0.3 }]));
 
-        let section = after[..end_idx].trim();
+        // Pure exponential at 1%/day halves around day 70
+        assert_eq!(smooth.half_life_days, 70.0);
+        assert!(shocked.half_life_days < smooth.half_life_days);
+        assert!(shocked.half_life_days > 30.0);
 
-        if section.is_empty() || section == ":" {
-            empty.push(h.to_string());
-            continue;
-        }
+        // The step lands exactly on the shock day
+        let before = shocked.confidence_timeline[29].confidence;
+        let at = shocked.confidence_timeline[30].confidence;
+        assert!(at < before * 0.75);
+        assert!((smooth.confidence_timeline[30].confidence * 0.7 - at).abs() < 1e-9);
+    }
 
-        let has_list_item = bullet_re.is_match(section) || num_re.is_match(section);
-        let word_count = word_re.find_iter(section).count();
+    #[test]
+    fn test_decay_models() {
+        let config = |decay_model: DecayModel| DecisionDecayConfig {
+            initial_confidence: 80.0,
+            decay_factors: vec![DecayFactor {
+                name: "Market".to_string(),
+                decay_rate: 0.5,
+                volatility: 0.1,
+            }],
+            time_horizon_days: 365,
+            start_date: None,
+            half_life_tolerance: 2.0,
+            shock_events: vec![],
+            decay_model,
+        };
 
-        if !has_list_item && word_count < 1 {
-            empty.push(h.to_string());
+        // Linear: same drop every day until it hits zero, halved at day 100
+        let linear = calculate_decision_decay(config(DecayModel::Linear));
+        let step = linear.confidence_timeline[0].confidence - linear.confidence_timeline[1].confidence;
+        assert!((step - 0.4).abs() < 1e-9);
+        for w in linear.confidence_timeline[..150].windows(2) {
+            assert!((w[0].confidence - w[1].confidence - step).abs() < 1e-9);
         }
+        assert_eq!(linear.half_life_days, 100.0);
+
+        // Sigmoid: per-day drop is steepest right at the midpoint
+        let sigmoid = calculate_decision_decay(config(DecayModel::Sigmoid { midpoint_day: 120, steepness: 0.1 }));
+        let timeline = &sigmoid.confidence_timeline;
+        assert!((timeline[0].confidence - 80.0).abs() < 1e-9);
+        let steepest = timeline
+            .windows(2)
+            .max_by(|a, b| {
+                let da = a[0].confidence - a[1].confidence;
+                let db = b[0].confidence - b[1].confidence;
+                da.partial_cmp(&db).unwrap()
+            })
+            .unwrap()[0]
+            .day;
+        assert!((119..=120).contains(&steepest));
+        assert!((sigmoid.half_life_days - 120.0).abs() <= 1.0);
     }
 
-    (missing, dupes, empty)
-}
-
-fn count_next_actions(normalized_upper: &str) -> usize {
-    let header_re = Regex::new(r"(?m)^\s*NEXT ACTIONS\s*:?\s*$").unwrap();
-    let m = match header_re.find(normalized_upper) {
-        Some(x) => x,
-        None => return 0,
-    };
-
-    let after = &normalized_upper[m.end()..];
-
-    let stop_re = Regex::new(
-        r"(?m)^\s*(BEST OPTION|RATIONALE|TOP RISKS|ASSUMPTIONS TO VALIDATE|ASSUMPTIONS|HALF-LIFE|BLIND SPOTS)\s*:?\s*$",
-    )
-    .unwrap();
-
-    let end_idx = stop_re
-        .find(after)
-        .map(|x| x.start())
-        .unwrap_or(after.len());
+    #[test]
+    fn test_critical_review_date_iso() {
+        let config = |start_date: Option<&str>| DecisionDecayConfig {
+            initial_confidence: 80.0,
+            decay_factors: vec![DecayFactor {
+                name: "Market".to_string(),
+                decay_rate: 0.5,
+                volatility: 0.1,
+            }],
+            time_horizon_days: 365,
+            start_date: start_date.map(str::to_string),
+            half_life_tolerance: 2.0,
+            shock_events: vec![],
+            decay_model: DecayModel::Linear,
+        };
 
-    let section = after[..end_idx].trim();
-    if section.is_empty() {
-        return 0;
+        // Linear at 0.5%/day halves at day 100, so review 50 days in
+        let result = calculate_decision_decay(config(Some("2024-01-01")));
+        assert_eq!(result.half_life_days, 100.0);
+        assert_eq!(result.critical_review_date, "2024-02-20");
+
+        // Rolls over into the next year
+        let result = calculate_decision_decay(config(Some("2023-12-15T09:30:00Z")));
+        assert_eq!(result.critical_review_date, "2024-02-03");
+        let result = calculate_decision_decay(config(Some("2024-12-01")));
+        assert_eq!(result.critical_review_date, "2025-01-20");
+
+        let result = calculate_decision_decay(config(None));
+        assert_eq!(result.critical_review_date, "50 days from now");
+
+        // A zero rate never halves; no calendar date to give
+        let mut flat = config(Some("2024-01-01"));
+        flat.decay_factors[0].decay_rate = 0.0;
+        let result = calculate_decision_decay(flat);
+        assert!(result.half_life_days.is_infinite());
+        assert_eq!(result.critical_review_date, "inf days from now");
     }
 
-    let bullet_re = Regex::new(r"(?m)^\s*[-*]\s+\S+").unwrap();
-    let num_re = Regex::new(r"(?m)^\s*\d{1,2}[\.\)]\s+\S+").unwrap();
-
-    let bullets = bullet_re.find_iter(section).count();
-    let nums = num_re.find_iter(section).count();
+    #[test]
+    fn test_compare_reports() {
+        let before = "BEST OPTION:\nMigrate payments to the regional cluster by March.\n\nRATIONALE:\n- Cuts APAC checkout latency from 820 ms to 280 ms\n\nTOP RISKS:\n- Data residency review slips\n\nASSUMPTIONS TO VALIDATE:\n- Provider supports the region\n\nHALF-LIFE:\n90 days\n\nBLIND SPOTS:\n- India traffic routing\n";
+        let after = format!(
+            "{}\nNEXT ACTIONS:\n1. Priya files the residency review by Feb 6\n2. Marco confirms the endpoint by Feb 10\n3. Lena gets finance sign-off by Feb 13\n4. Marco runs shadow traffic from Feb 16\n5. Priya drafts the rollback plan by Feb 20\n6. Lena books the cutover window\n",
+            before
+        );
 
-    bullets.max(nums)
-}
+        let diff = compare_reports(before, &after, ScoringConfig::default());
+        assert_eq!(diff.headers_added, vec!["NEXT ACTIONS".to_string()]);
+        assert!(diff.headers_removed.is_empty());
+        assert!(diff.score_delta > 0);
+        assert_eq!(diff.score_delta, diff.score_after as i32 - diff.score_before as i32);
+        assert_eq!(diff.quality_deltas.len(), 10);
+        let overall = diff.quality_deltas.iter().find(|d| d.metric == "overall_quality").unwrap();
+        assert!((overall.delta - (overall.after - overall.before)).abs() < 1e-12);
+
+        // Reversed, the header is reported as removed
+        let back = compare_reports(&after, before, ScoringConfig::default());
+        assert_eq!(back.headers_removed, vec!["NEXT ACTIONS".to_string()]);
+        assert_eq!(back.score_delta, -diff.score_delta);
+    }
 
-fn looks_truncated(cleaned: &str) -> bool {
-    let t = cleaned.trim_end();
+    #[test]
+    fn test_custom_vague_words() {
+        let text = "BEST OPTION:\nFile the motion to dismiss; the claim is arguably time-barred and reasonably likely to fail.\n";
+        let default = score_report_text(text, ScoringConfig::default());
+        let legal = score_report_text(
+            text,
+            ScoringConfig {
+                vague_words: Some(to_strings(&["arguably", "reasonably"])),
+                ..ScoringConfig::default()
+            },
+        );
+        assert!(
+            legal.quality_metrics.specificity_score < default.quality_metrics.specificity_score,
+            "{} vs {}",
+            legal.quality_metrics.specificity_score,
+            default.quality_metrics.specificity_score
+        );
 
-    if t.is_empty() {
-        return true;
+        // Custom action verbs replace the defaults
+        let plain = score_report_text("NEXT ACTIONS:\n1. Serve the defendant\n", ScoringConfig::default());
+        let verbs = score_report_text(
+            "NEXT ACTIONS:\n1. Serve the defendant\n",
+            ScoringConfig { action_verbs: Some(to_strings(&["serve"])), ..ScoringConfig::default() },
+        );
+        assert!(verbs.quality_metrics.actionability_score > plain.quality_metrics.actionability_score);
     }
 
-    let bad_endings = ["...", "…", "```", "**", "__", "- ", "* ", "1.", "2.", "3."];
-    if bad_endings.iter().any(|x| t.ends_with(x)) {
-        return true;
+    #[test]
+    fn test_lexical_diversity() {
+        let repetitive = "We will improve the process. We will improve the process again. \
+            We will improve the process and we will improve the process. "
+            .repeat(5);
+        let varied = "Checkout latency in Singapore averages 820 ms because payment calls cross \
+            the Pacific to Virginia. A regional cluster already serves catalog traffic, and a \
+            load test measured 280 ms at p95. Finance must approve roughly four thousand dollars \
+            monthly; legal still needs to review data residency before cutover. If rate limits \
+            differ, the vendor can raise ours within a week, according to their support team.";
+
+        let low = calculate_lexical_diversity(&repetitive);
+        let high = calculate_lexical_diversity(varied);
+        assert!(high > low, "varied {} vs repetitive {}", high, low);
+        assert!(low < 0.3);
+        assert!(high > 0.7);
+        assert_eq!(calculate_lexical_diversity(""), 0.0);
     }
 
-    if t.ends_with('(') || t.ends_with(':') || t.ends_with(',') {
-        return true;
+    #[test]
+    fn test_passive_voice_ratio() {
+        let active = "Dana approves the budget by Friday. Marco migrates the payment service. \
+            The team ships the rollback plan next week.";
+        let passive = "The budget was approved last quarter. The service was migrated without review. \
+            Mistakes were made. The rollback plan has been quickly written.";
+
+        assert_eq!(passive_voice_ratio(active), 0.0);
+        assert_eq!(passive_voice_ratio(passive), 1.0);
+        assert_eq!(passive_voice_ratio(&format!("{} {}", active, passive)), 4.0 / 7.0);
+        assert_eq!(passive_voice_ratio(""), 0.0);
+
+        let lexicon = Lexicon::english();
+        let a = calculate_quality_metrics(active, &lexicon, 40, &QualityWeights::default());
+        let p = calculate_quality_metrics(passive, &lexicon, 40, &QualityWeights::default());
+        assert_eq!(a.passive_voice_ratio, 0.0);
+        assert_eq!(p.passive_voice_ratio, 1.0);
+        assert!(p.actionability_score <= calculate_actionability_score(passive, &lexicon.action_verbs) * 0.8 + 1e-12);
     }
 
-    let lines: Vec<&str> = t.lines().collect();
-    if lines.len() >= 10 {
-        if let Some(last) = lines.last() {
-            if last.trim().len() <= 3 {
-                return true;
-            }
-        }
+    #[test]
+    fn test_detect_contradictions() {
+        let report = "BEST OPTION:\nAdopt the vendor's hosted queue.\n\nRATIONALE:\n- This is a low risk, cheap change.\n\nTOP RISKS:\n- Severe outage if the vendor's region fails\n- Migration is costly if we exceed the free tier\n\nASSUMPTIONS TO VALIDATE:\n- Vendor SLA holds\n\nHALF-LIFE:\n90 days\n\nBLIND SPOTS:\n- Support quality\n\nNEXT ACTIONS:\n1. Dana signs the contract by Friday\n2. Marco moves staging\n3. Marco moves production\n4. Lena updates runbooks\n5. Lena trains on-call\n6. Dana reviews costs monthly\n";
+
+        let result = score_report_text(report, ScoringConfig::default());
+        assert_eq!(
+            result.contradictions,
+            vec![
+                "RATIONALE says \"low risk\" but TOP RISKS says \"severe\"".to_string(),
+                "RATIONALE says \"cheap\" but TOP RISKS says \"costly\"".to_string(),
+            ]
+        );
+        assert!(result.penalties.contains(&Penalty { label: "Contradictions".to_string(), points: 8 }));
+        assert_eq!(result.score, 92);
+
+        // Same words inside one section are not a contradiction
+        let mut sections = HashMap::new();
+        sections.insert("TOP RISKS".to_string(), "Low risk of delay; severe if it happens".to_string());
+        assert!(detect_contradictions(&sections).is_empty());
+
+        // Word boundaries: "unproven" does not count as "proven"
+        sections.insert("RATIONALE".to_string(), "An unproven approach".to_string());
+        sections.insert("BLIND SPOTS".to_string(), "It is experimental".to_string());
+        assert!(detect_contradictions(&sections).is_empty());
     }
 
-    false
-}
-
-// ============================================================================
-// TESTS
-// ============================================================================
+    #[test]
+    fn test_sections_map() {
+        let actions = "1. Dana signs the contract by Friday\n2. Marco moves staging (owner: Marco)\n   - includes the DNS cutover";
+        let report = format!(
+            "BEST OPTION:\nAdopt the hosted queue.\n\nRATIONALE:\n- Saves two engineers a quarter\n\nNEXT ACTIONS:\n{}\n\n",
+            actions
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let result = score_report_text(&report, ScoringConfig::default());
+        assert_eq!(result.sections.get("NEXT ACTIONS").map(String::as_str), Some(actions));
+        assert_eq!(result.sections["BEST OPTION"], "Adopt the hosted queue.");
+        assert!(!result.sections.contains_key("TOP RISKS"));
+    }
 
     #[test]
-    fn test_basic_scoring() {
-        let input = r#"
-BEST OPTION:
-Choose Option A for maximum ROI.
+    fn test_next_action_quality() {
+        let report = "NEXT ACTIONS:\n1. Deploy the regional queue (owner: Dana) by 2026-02-06\n2. Improve things\n";
+        let result = score_report_text(report, ScoringConfig { min_next_actions: 2, ..ScoringConfig::default() });
+
+        assert_eq!(
+            result.next_action_quality,
+            vec![
+                ActionQuality {
+                    action: "Deploy the regional queue (owner: Dana) by 2026-02-06".to_string(),
+                    has_verb: true,
+                    has_owner: true,
+                    has_deadline: true,
+                },
+                ActionQuality {
+                    action: "Improve things".to_string(),
+                    has_verb: false,
+                    has_owner: false,
+                    has_deadline: false,
+                },
+            ]
+        );
+        assert!(!result.penalties.iter().any(|p| p.label == "Actions missing owner or deadline"));
 
-RATIONALE:
-- Cost effective
-- Proven technology
-- Team expertise
+        let strict = score_report_text(
+            report,
+            ScoringConfig { min_next_actions: 2, require_smart_actions: true, ..ScoringConfig::default() },
+        );
+        assert!(strict
+            .penalties
+            .contains(&Penalty { label: "Actions missing owner or deadline".to_string(), points: 2 }));
+        assert_eq!(strict.score, result.score - 2);
+
+        // Table rows take owner and due from their columns
+        let table = "| Action | Owner | Due |\n|---|---|---|\n| Launch beta | Dana | Mar 3 |\n| Tidy docs | | |\n";
+        let quality = next_action_quality(table, &Lexicon::english().action_verbs);
+        assert!(quality[0].has_verb && quality[0].has_owner && quality[0].has_deadline);
+        assert!(!quality[1].has_owner && !quality[1].has_deadline);
+    }
 
-TOP RISKS:
-- Market volatility
-- Technical debt
-- Resource constraints
+    #[test]
+    fn test_duplicate_next_actions() {
+        let report = "NEXT ACTIONS:\n\
+            1. Schedule the vendor kickoff meeting\n\
+            2. Schedule a kickoff meeting with the vendor\n\
+            3. Draft the migration runbook\n\
+            4. Draft the migration runbook.\n\
+            5. Book the load test window\n\
+            6. Book a window for the load test\n";
+
+        let result = score_report_text(report, ScoringConfig::default());
+        assert_eq!(
+            result.duplicate_next_actions,
+            vec![
+                "Schedule a kickoff meeting with the vendor".to_string(),
+                "Draft the migration runbook.".to_string(),
+                "Book a window for the load test".to_string(),
+            ]
+        );
+        assert_eq!(result.next_actions_count, 3);
+        assert!(!result.next_actions_ok);
+        assert!(result.notes.contains(&"3 duplicate NEXT ACTIONS not counted".to_string()));
+
+        // Related but distinct tasks are kept
+        let distinct = to_strings(&["Migrate staging to the new cluster", "Migrate production to the new cluster after sign-off"]);
+        assert!(duplicate_items(&distinct, &Lexicon::english().stopwords).is_empty());
+
+        // The public helper agrees with the dedupe
+        assert!(is_same_item("Schedule the vendor kickoff meeting", "Schedule a kickoff meeting with the vendor"));
+        assert!(!is_same_item(&distinct[0], &distinct[1]));
+        assert_eq!(item_similarity("Draft the runbook", "draft, the RUNBOOK"), 1.0);
+    }
 
-ASSUMPTIONS TO VALIDATE:
-- Budget approved
-- Team available
-- Timeline feasible
+    #[test]
+    fn test_min_section_words() {
+        let input = minimal_template(&ScoringConfig::default())
+            .replace("- [FILL IN: rationale]", "- Cheaper, faster, safer");
+        let cfg = ScoringConfig { min_section_words: 10, ..ScoringConfig::default() };
+
+        let result = score_report_text(&input, cfg);
+        assert!(result.thin_sections.contains(&"RATIONALE".to_string()));
+        assert!(!result.empty_sections.contains(&"RATIONALE".to_string()));
+        assert!(result.penalties.iter().any(|p| p.label == "Thin sections"));
+
+        // Off by default
+        let result = score_report_text(&input, ScoringConfig::default());
+        assert!(result.thin_sections.is_empty());
+        assert_eq!(result.score, 100);
+    }
 
-HALF-LIFE:
-6 months - review quarterly
+    #[test]
+    fn test_header_order() {
+        let template = minimal_template(&ScoringConfig::default());
+        let blind_spots = "BLIND SPOTS:\n- [FILL IN: blind spots]\n\n";
+        let input = template
+            .replace(blind_spots, "")
+            .replace("TOP RISKS:", &format!("{}TOP RISKS:", blind_spots));
+        assert!(input.find("BLIND SPOTS").unwrap() < input.find("TOP RISKS").unwrap());
+
+        let cfg = ScoringConfig { enforce_header_order: true, ..ScoringConfig::default() };
+        let result = score_report_text(&input, cfg.clone());
+        assert_eq!(result.misordered_headers, vec!["BLIND SPOTS".to_string()]);
+        assert!(result.penalties.contains(&Penalty { label: "Misordered headers".to_string(), points: 3 }));
+        assert_eq!(result.score, 97);
+
+        // In order, or not enforced: nothing flagged
+        assert!(score_report_text(&template, cfg).misordered_headers.is_empty());
+        assert!(score_report_text(&input, ScoringConfig::default()).misordered_headers.is_empty());
+    }
 
-BLIND SPOTS:
-- Competitor moves
-- Regulatory changes
+    #[test]
+    fn test_render_markdown() {
+        let input = minimal_template(&ScoringConfig::default())
+            .replace("BLIND SPOTS:", "")
+            .replace("HALF-LIFE:", "");
+        let result = score_report_text(&input, ScoringConfig::default());
+        let md = render_markdown(&result);
+
+        assert!(md.contains(&format!("**Score:** {}/100", result.score)));
+        for h in &result.missing_headers {
+            assert!(md.contains(&format!("- [ ] {} (missing)", h)), "{}", h);
+        }
+        assert!(md.contains("- [x] BEST OPTION"));
+        // Checklist keeps the template order
+        assert!(md.find("BEST OPTION").unwrap() < md.find("NEXT ACTIONS").unwrap());
+        assert!(md.contains("| Clarity | "));
+        assert!(md.contains("## Notes\n\n- Missing headers penalty: -24"));
+    }
 
-NEXT ACTIONS:
-1. Get budget approval by Friday
-2. Schedule kickoff meeting
-3. Assign project lead
-4. Create project charter
-5. Set up tracking
-6. Send stakeholder update
-"#;
+    #[cfg(feature = "schema")]
+    #[test]
+    fn test_export_schemas() {
+        let doc: serde_json::Value = serde_json::from_str(&export_schemas()).unwrap();
+        let defs = &doc["$defs"];
+        assert_eq!(defs["ScoreResult"]["properties"]["score"]["type"], "integer");
+        assert!(defs["MonteCarloResult"]["properties"]["risk_of_failure"].is_object());
+        // Nested types are bundled so `$ref`s resolve within the document
+        assert!(defs["QualityMetrics"].is_object());
+        assert_eq!(defs["ScoreResult"]["properties"]["quality_metrics"]["$ref"], "#/$defs/QualityMetrics");
+    }
 
-        let result = score_report_text(input, ScoringConfig::default());
-        assert!(result.score >= 80);
-        assert!(!result.must_repair);
-        assert_eq!(result.missing_headers.len(), 0);
+    #[test]
+    fn test_try_score_report_text_rejects_malformed_header() {
+        let template = minimal_template(&ScoringConfig::default());
+        let mut cfg = ScoringConfig::default();
+        cfg.required_headers.push("OPEN\nQUESTIONS".into());
+        assert!(matches!(
+            try_score_report_text(&template, cfg),
+            Err(ScoreError::InvalidHeader(h)) if h == "OPEN\nQUESTIONS"
+        ));
+
+        let mut cfg = ScoringConfig::default();
+        cfg.required_headers.push("   ".into());
+        assert!(try_score_report_text(&template, cfg).is_err());
+
+        // The header scan compiles this one case-sensitively, but the
+        // case-insensitive section pattern the exit-plan check builds to end
+        // BLIND SPOTS (K also folds to the Kelvin sign) is too big: an error,
+        // not a panic
+        let huge = "K".repeat(50_000);
+        let cfg = ScoringConfig { required_headers: vec![huge.clone()], ..ScoringConfig::default() };
+        let input = format!("{}:\nbody\n\nBLIND SPOTS:\n- Rollback untested\n", huge);
+        assert!(matches!(try_score_report_text(&input, cfg), Err(ScoreError::Pattern(_))));
+
+        // Well-formed configs score exactly as the panicking wrapper does
+        let cfg = ScoringConfig::default();
+        let ok = try_score_report_text(&template, cfg.clone()).unwrap();
+        assert_eq!(ok.score, score_report_text(&template, cfg).score);
     }
 
     #[test]
-    fn test_monte_carlo() {
-        let risks = vec![
-            RiskFactor {
-                name: "Market Risk".to_string(),
-                probability: 0.3,
-                impact_low: 5.0,
-                impact_high: 15.0,
-                category: RiskCategory::Market,
-            },
-            RiskFactor {
-                name: "Technical Risk".to_string(),
-                probability: 0.2,
-                impact_low: 10.0,
-                impact_high: 25.0,
-                category: RiskCategory::Technical,
-            },
+    fn test_score_report_reader_matches_text() {
+        let cfg = ScoringConfig::default();
+        let report = format!(
+            "\r\n\r\n  ## Report\r\n---\r\n{}\r\n```\r\nappendix   \r\n",
+            minimal_template(&cfg).replace('\n', "\r\n")
+        );
+        let cases = [report.as_str(), "just some text...", "", "TOP RISKS:\n- Vendor slips\n\n\n"];
+        let configs = [
+            cfg.clone(),
+            ScoringConfig { enforce_header_order: true, min_section_words: 3, ..cfg.clone() },
+            ScoringConfig { header_match_mode: HeaderMatchMode::Fuzzy(2), ..cfg.clone() },
+            ScoringConfig { ignore_quoted_headers: true, ..cfg.clone() },
         ];
+        for cfg in configs {
+            for input in cases {
+                let streamed = score_report_reader(std::io::Cursor::new(input), cfg.clone());
+                let whole = score_report_text(input, cfg.clone());
+                assert_eq!(serde_json::to_value(&streamed).unwrap(), serde_json::to_value(&whole).unwrap());
+            }
+        }
 
-        let result = run_monte_carlo_simulation(
-            85.0,
-            &risks,
-            MonteCarloConfig {
-                iterations: 1000,
-                seed: Some(42),
-                confidence_level: 0.95,
-            },
-        );
+        // Read errors surface instead of scoring a partial report
+        let bad = b"BEST OPTION:\nOption A\n\xff\xfe\nRATIONALE:\n".as_slice();
+        assert!(matches!(try_score_report_reader(bad, cfg.clone()), Err(ScoreError::Io(_))));
 
-        assert!(result.mean_score > 70.0 && result.mean_score < 90.0);
-        assert!(result.std_dev > 0.0);
-        assert_eq!(result.iterations_run, 1000);
+        let mut cfg = cfg;
+        cfg.required_headers.push(String::new());
+        assert!(matches!(
+            try_score_report_reader(report.as_bytes(), cfg),
+            Err(ScoreError::InvalidHeader(_))
+        ));
     }
 
     #[test]
-    fn test_sensitivity_analysis() {
-        let config = SensitivityConfig {
-            variables: vec![
-                SensitivityVariable {
-                    name: "Budget".to_string(),
-                    base_value: 100000.0,
-                    min_value: 50000.0,
-                    max_value: 150000.0,
-                    weight: 0.8,
-                },
-                SensitivityVariable {
-                    name: "Timeline".to_string(),
-                    base_value: 90.0,
-                    min_value: 60.0,
-                    max_value: 120.0,
-                    weight: 0.5,
-                },
-            ],
-            step_count: 10,
+    fn test_truncation_repair_threshold_and_labels() {
+        let report = "BEST OPTION:\nAdopt the vendor's hosted queue.\n\nRATIONALE:\n- Cuts on-call load by 30%\n\nTOP RISKS:\n- Vendor outage\n\nASSUMPTIONS TO VALIDATE:\n- Vendor SLA holds\n\nHALF-LIFE:\n90 days\n\nBLIND SPOTS:\n- Support quality\n\nNEXT ACTIONS:\n1. Dana signs the contract by Friday\n2. Marco moves staging\n3. Marco moves production\n4. Lena updates runbooks\n5. Lena trains on-call\n6. Dana reviews costs monthly...";
+        let cfg = ScoringConfig {
+            penalty_weights: PenaltyWeights { truncation: 10, ..PenaltyWeights::default() },
+            ..ScoringConfig::default()
         };
 
-        let result = run_sensitivity_analysis(80.0, config);
-        
-        assert_eq!(result.variable_impacts.len(), 2);
-        assert_eq!(result.tornado_chart_data.len(), 2);
-    }
+        let result = score_report_text(report, cfg.clone());
+        assert!(result.truncation_suspected);
+        assert_eq!(result.score, 90);
+        assert!(result.must_repair);
+        assert_eq!(result.finish_reason_hint, "LIKELY_TRUNCATED");
 
-    #[test]
-    fn test_decision_decay() {
-        let config = DecisionDecayConfig {
-            initial_confidence: 90.0,
-            decay_factors: vec![
-                DecayFactor {
-                    name: "Market Changes".to_string(),
-                    decay_rate: 0.5,
-                    volatility: 0.2,
-                },
-            ],
-            time_horizon_days: 365,
+        let labels = FinishReasonLabels {
+            truncated: "length".to_string(),
+            incomplete: "content_filter".to_string(),
+            ok: "stop".to_string(),
+        };
+        let cfg = ScoringConfig {
+            truncation_repair_below: 90,
+            finish_reason_rules: labels.rules(),
+            ..cfg
         };
+        let result = score_report_text(report, cfg);
+        assert!(!result.must_repair);
+        assert_eq!(result.finish_reason_hint, "length");
+    }
 
-        let result = calculate_decision_decay(config);
-        
-        assert!(result.half_life_days > 0.0);
-        assert!(!result.confidence_timeline.is_empty());
-        assert!(result.stability_score >= 0.0 && result.stability_score <= 100.0);
+    #[test]
+    fn test_truncation_unfinished_sentence() {
+        let body = "BEST OPTION:\nProceed with Option A.\n\nRATIONALE:\n";
+        assert!(looks_truncated(&format!("{}The decision should be", body), true));
+        assert!(!looks_truncated(&format!("{}Proceed with Option A.", body), true));
+        // Short labels, list items and headers are not sentences
+        assert!(!looks_truncated(&format!("{}90 days", body), true));
+        assert!(!looks_truncated(&format!("{}- Dana signs the contract by Friday", body), true));
+
+        // Off restores the suffix-only checks
+        assert!(!looks_truncated(&format!("{}The decision should be", body), false));
+
+        let mut cfg = ScoringConfig::default();
+        let input = format!("{}\nThe decision should be", minimal_template(&cfg));
+        assert!(score_report_text(&input, cfg.clone()).truncation_suspected);
+        cfg.check_sentence_completeness = false;
+        assert!(!score_report_text(&input, cfg).truncation_suspected);
     }
 }
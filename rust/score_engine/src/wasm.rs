@@ -1,6 +1,8 @@
-use super::{score_report_text, ScoringConfig};
-use console_error_panic_hook;
-use serde_wasm_bindgen;
+use super::{
+    run_monte_carlo_simulation, score_report_text, IncrementalScorer, MonteCarloConfig, RiskFactor,
+    ScoringConfig,
+};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen(start)]
@@ -14,5 +16,119 @@ pub fn score_report(input: String) -> JsValue {
     let cfg = ScoringConfig::default();
     let result = score_report_text(&input, cfg);
 
-    serde_wasm_bindgen::to_value(&result).unwrap_or_else(|_| JsValue::NULL)
+    serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+}
+
+/// JS-side overrides for `ScoringConfig`; omitted fields keep their defaults.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct JsScoringConfig {
+    pub min_next_actions: Option<usize>,
+    pub min_floor_score: Option<u32>,
+    pub language: Option<String>,
+    pub structural_weight: Option<f64>,
+}
+
+impl JsScoringConfig {
+    fn into_config(self) -> ScoringConfig {
+        let mut cfg = ScoringConfig::default();
+        if let Some(v) = self.min_next_actions {
+            cfg.min_next_actions = v;
+        }
+        if let Some(v) = self.min_floor_score {
+            cfg.min_floor_score = v;
+        }
+        if self.language.is_some() {
+            cfg.language = self.language;
+        }
+        if let Some(v) = self.structural_weight {
+            cfg.structural_weight = v;
+        }
+        cfg
+    }
+}
+
+#[wasm_bindgen]
+pub fn score_report_with_config(input: String, config: JsValue) -> JsValue {
+    let overrides: JsScoringConfig = if config.is_undefined() || config.is_null() {
+        JsScoringConfig::default()
+    } else {
+        serde_wasm_bindgen::from_value(config).unwrap_or_default()
+    };
+    let result = score_report_text(&input, overrides.into_config());
+
+    serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+}
+
+/// Returned to JS in place of a result when the input can't be parsed.
+#[derive(Debug, Serialize)]
+struct JsError {
+    error: String,
+}
+
+fn js_error(message: String) -> JsValue {
+    serde_wasm_bindgen::to_value(&JsError { error: message }).unwrap_or(JsValue::NULL)
+}
+
+/// Monte Carlo over `risks_json` starting from `base_score`.
+///
+/// `risks_json` is an array of risks:
+/// `[{"name": "Vendor slips", "probability": 0.3, "impact_low": 5, "impact_high": 15, "category": "Operational"}]`
+/// where `category` is one of `Technical`, `Market`, `Financial`, `Operational`,
+/// `Strategic`, `External`. Optional `impact_distribution` (default `"Uniform"`)
+/// and `direction` (`"Threat"` or `"Opportunity"`) may be added per risk.
+///
+/// `config_json` is a `MonteCarloConfig` object; omitted fields (or an empty
+/// string) keep the defaults, e.g. `{"iterations": 5000, "seed": 42}`.
+///
+/// Returns a `MonteCarloResult`, or `{ "error": "..." }` if either JSON is
+/// invalid or `iterations` is 0.
+#[wasm_bindgen]
+pub fn run_monte_carlo(base_score: f64, risks_json: String, config_json: String) -> JsValue {
+    let risks: Vec<RiskFactor> = match serde_json::from_str(&risks_json) {
+        Ok(r) => r,
+        Err(e) => return js_error(format!("invalid risks: {}", e)),
+    };
+    let config: MonteCarloConfig = if config_json.trim().is_empty() {
+        MonteCarloConfig::default()
+    } else {
+        match serde_json::from_str(&config_json) {
+            Ok(c) => c,
+            Err(e) => return js_error(format!("invalid config: {}", e)),
+        }
+    };
+    if config.iterations == 0 {
+        return js_error("invalid config: iterations must be at least 1".to_string());
+    }
+    let result = run_monte_carlo_simulation(base_score, &risks, config);
+
+    serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+}
+
+/// Live-editing scorer: append text as it is typed, read the latest result.
+/// Completed lines are header-scanned once as they arrive; `current()` after
+/// an append only rescans the last line and reruns the document-wide checks
+/// (see the native `IncrementalScorer`). Repeated reads in between are cached.
+#[wasm_bindgen(js_name = IncrementalScorer)]
+pub struct WasmIncrementalScorer {
+    inner: IncrementalScorer,
+}
+
+#[wasm_bindgen(js_class = IncrementalScorer)]
+impl WasmIncrementalScorer {
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            inner: IncrementalScorer::new(ScoringConfig::default()),
+        }
+    }
+
+    pub fn append(&mut self, text: String) {
+        self.inner.append(&text);
+    }
+
+    pub fn current(&mut self) -> JsValue {
+        serde_wasm_bindgen::to_value(self.inner.current()).unwrap_or(JsValue::NULL)
+    }
 }
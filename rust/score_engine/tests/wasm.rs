@@ -0,0 +1,54 @@
+#![cfg(target_arch = "wasm32")]
+
+use score_engine::wasm::{run_monte_carlo, score_report, score_report_with_config, WasmIncrementalScorer};
+use score_engine::{MonteCarloResult, ScoreResult};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+fn result_of(value: JsValue) -> ScoreResult {
+    serde_wasm_bindgen::from_value(value).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn incremental_matches_one_shot() {
+    let part1 = "BEST OPTION:\nOption A.\n\nRATIONALE:\n- Cheapest path\n\n";
+    let part2 = "NEXT ACTIONS:\n1. Draft plan\n2. Book review\n";
+
+    let mut scorer = WasmIncrementalScorer::new();
+    scorer.append(part1.to_string());
+    scorer.append(part2.to_string());
+
+    let incremental = result_of(scorer.current());
+    let one_shot = result_of(score_report(format!("{}{}", part1, part2)));
+    assert_eq!(incremental.score, one_shot.score);
+    assert_eq!(incremental.notes, one_shot.notes);
+}
+
+#[wasm_bindgen_test]
+fn composite_respects_config_weight() {
+    let input = "BEST OPTION:\nOption A.\n\nNEXT ACTIONS:\n1. Draft plan\n";
+
+    let default = result_of(score_report(input.to_string()));
+    assert!(default.composite_score > 0.0);
+
+    let config = js_sys::JSON::parse(r#"{"structural_weight": 1.0}"#).unwrap();
+    let structural_only = result_of(score_report_with_config(input.to_string(), config));
+    assert_eq!(structural_only.composite_score, structural_only.score as f64);
+}
+
+#[wasm_bindgen_test]
+fn monte_carlo_round_trip() {
+    let risks = r#"[{"name": "Vendor slips", "probability": 0.3, "impact_low": 5, "impact_high": 15, "category": "Operational"}]"#;
+    let config = r#"{"iterations": 500, "seed": 1}"#;
+    let value = run_monte_carlo(80.0, risks.to_string(), config.to_string());
+    let result: MonteCarloResult = serde_wasm_bindgen::from_value(value).unwrap();
+    assert_eq!(result.iterations_run, 500);
+
+    let error = run_monte_carlo(80.0, "not json".to_string(), String::new());
+    let message = js_sys::Reflect::get(&error, &JsValue::from_str("error")).unwrap();
+    assert!(message.as_string().unwrap().starts_with("invalid risks"));
+
+    let error = run_monte_carlo(80.0, risks.to_string(), r#"{"iterations": 0}"#.to_string());
+    let message = js_sys::Reflect::get(&error, &JsValue::from_str("error")).unwrap();
+    assert!(message.as_string().unwrap().starts_with("invalid config"));
+}